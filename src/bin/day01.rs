@@ -1,7 +1,6 @@
-use std::fs;
 use thiserror::Error;
 
-use advent_of_code_2025::Part;
+use advent_of_code_2025::{Part, input::read_input};
 use anyhow::Result;
 
 #[derive(Error, Debug, PartialEq)]
@@ -19,38 +18,71 @@ enum Direction {
 const INITIAL_DIAL_POSITION: isize = 50;
 const DIAL_LENGTH: isize = 100;
 
+/// A circular dial with positions `0..length`, where the pin points at `position`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Dial {
+    length: isize,
+    position: isize,
+}
+
+impl Default for Dial {
+    /// The puzzle's dial, i.e. [DIAL_LENGTH] positions starting at [INITIAL_DIAL_POSITION].
+    fn default() -> Self {
+        Self {
+            length: DIAL_LENGTH,
+            position: INITIAL_DIAL_POSITION,
+        }
+    }
+}
+
+impl Dial {
+    /// Turns the dial in `direction` for a number of `distance`, and returns the total number of
+    /// times `0` is hit during the rotation.
+    ///
+    /// Note: Starting at `0` alone does not count as hitting zero, but landing on `0` does.
+    fn turn(&mut self, direction: Direction, distance: isize) -> usize {
+        let raw_final_pos: isize = match direction {
+            Direction::Left => self.position - distance,
+            Direction::Right => self.position + distance,
+        };
+
+        let mut zero_hits = (raw_final_pos / self.length).unsigned_abs();
+        if (self.position > 0 && raw_final_pos < 0) || raw_final_pos == 0 {
+            zero_hits += 1;
+        }
+
+        self.position = raw_final_pos.rem_euclid(self.length);
+        zero_hits
+    }
+}
+
 /// Day 1: Secret Entrance
 ///
 /// - Part One: Only counts number of times dial points to `0` at the end of each move.
 /// - Part Two: Counts number of times the dial hits `0` during a rotation or end of one.
-fn solve_day01(input: &str, part: Part) -> Result<isize, SolverError> {
+fn solve_day01(input: &str, part: Part) -> Result<usize, SolverError> {
+    solve_day01_with_dial(input, part, Dial::default())
+}
+
+/// Same as [solve_day01], but turns the given `dial` instead of the puzzle's dial.
+fn solve_day01_with_dial(input: &str, part: Part, mut dial: Dial) -> Result<usize, SolverError> {
     let rotations: Vec<&str> = input.lines().filter(|&line| !line.is_empty()).collect();
 
-    let mut dial_position = INITIAL_DIAL_POSITION;
     let mut final_pos_zero_hit_count = 0;
     let mut total_zero_hit_count = 0;
 
     for rotation in rotations {
-        let direction = match &rotation[..1] {
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => return Err(SolverError::InvalidInput(rotation.into())),
-        };
-        let distance = rotation[1..]
-            .parse::<isize>()
-            .map_err(|_| SolverError::InvalidInput(rotation.into()))?;
+        let (direction, distance) = parse_rotation(rotation)?;
+        if dial.position == 0 {
+            final_pos_zero_hit_count += 1;
+        }
 
-        let (new_dial_position, zero_hits) = turn_dial(dial_position, direction, distance);
+        let zero_hits = dial.turn(direction, distance);
         // println!(
-        //     "The dial is rotated {rotation} to point at {dial_position}, hits zero for {zero_hits} times"
+        //     "The dial is rotated {rotation} to point at {}, hits zero for {zero_hits} times",
+        //     dial.position
         // );
-
-        if dial_position == 0 {
-            final_pos_zero_hit_count += 1;
-        }
         total_zero_hit_count += zero_hits;
-
-        dial_position = new_dial_position;
     }
 
     match part {
@@ -59,31 +91,52 @@ fn solve_day01(input: &str, part: Part) -> Result<isize, SolverError> {
     }
 }
 
-/// Turns the dial from starting position `start_pos` in `direction` for a number
-/// of `distance`.
-///
-/// Returns a tuple of `(final_pos, zero_hits)`:
-/// - `final_pos` - Final position of the pin
-/// - `zero_hits` - Total number of times `0` is hit during rotation
-///   - Note: `start_pos = 0` alone does not count as hitting zero
-fn turn_dial(start_pos: isize, direction: Direction, distance: isize) -> (isize, isize) {
-    let raw_final_pos: isize = match direction {
-        Direction::Left => start_pos - distance,
-        Direction::Right => start_pos + distance,
+/// Parses a rotation like `L68` into its direction and distance.
+fn parse_rotation(rotation: &str) -> Result<(Direction, isize), SolverError> {
+    let direction = match rotation.get(..1) {
+        Some("L") => Direction::Left,
+        Some("R") => Direction::Right,
+        _ => return Err(SolverError::InvalidInput(rotation.into())),
     };
+    let distance = rotation[1..]
+        .parse::<isize>()
+        .map_err(|_| SolverError::InvalidInput(rotation.into()))?;
+    Ok((direction, distance))
+}
 
-    let final_pos = (DIAL_LENGTH + raw_final_pos % DIAL_LENGTH) % DIAL_LENGTH;
-
-    let mut zero_hits = (raw_final_pos / DIAL_LENGTH).abs();
-    if (start_pos > 0 && raw_final_pos < 0) || raw_final_pos == 0 {
-        zero_hits += 1;
-    }
+/// Gets the dial position after each rotation, where the dial starts at [INITIAL_DIAL_POSITION].
+/// The initial position is not included.
+#[allow(dead_code)]
+fn position_history(input: &str) -> Result<Vec<isize>, SolverError> {
+    let mut dial = Dial::default();
+    input
+        .lines()
+        .filter(|&line| !line.is_empty())
+        .map(|rotation| {
+            let (direction, distance) = parse_rotation(rotation)?;
+            dial.turn(direction, distance);
+            Ok(dial.position)
+        })
+        .collect()
+}
 
-    (final_pos, zero_hits)
+/// Counts the total number of full revolutions across all rotations, where each rotation contributes
+/// `distance / DIAL_LENGTH` (rounded down) revolutions. Leftover clicks are not carried over to the
+/// next rotation, e.g. `R50` then `R50` is `0` revolutions.
+#[allow(dead_code)]
+fn count_revolutions(input: &str) -> Result<isize, SolverError> {
+    input
+        .lines()
+        .filter(|&line| !line.is_empty())
+        .map(|rotation| {
+            let (_, distance) = parse_rotation(rotation)?;
+            Ok(distance / DIAL_LENGTH)
+        })
+        .sum()
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day01.txt")?;
+    let input = read_input("puzzle_inputs/day01.txt")?;
     let part_1_solution = solve_day01(&input, Part::One)?;
     let part_2_solution = solve_day01(&input, Part::Two)?;
     println!("Part 1 Solution: {part_1_solution}");
@@ -97,41 +150,120 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     #[test]
-    fn test_turn_dial() {
+    fn test_count_revolutions() {
+        let input = "L68\nR250\nL399\nR100\nL99\nR1000";
+        assert_eq!(count_revolutions(input), Ok(2 + 3 + 1 + 10));
+
+        // Leftover clicks are not carried over
+        assert_eq!(count_revolutions("R50\nR50"), Ok(0));
+
+        // Puzzle example has no rotation of 100 or more
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        assert_eq!(count_revolutions(input), Ok(0));
+
+        assert_eq!(count_revolutions(""), Ok(0));
+        assert_eq!(
+            count_revolutions("R200\nX5"),
+            Err(SolverError::InvalidInput("X5".into()))
+        );
+    }
+
+    /// Turns a dial of `length` from `start_pos`, and returns `(final_pos, zero_hits)`.
+    fn turn_dial(
+        length: isize,
+        start_pos: isize,
+        direction: Direction,
+        distance: isize,
+    ) -> (isize, usize) {
+        let mut dial = Dial {
+            length,
+            position: start_pos,
+        };
+        let zero_hits = dial.turn(direction, distance);
+        (dial.position, zero_hits)
+    }
+
+    #[test]
+    fn test_dial_turn() {
         // No overflow
-        assert_eq!(turn_dial(11, Direction::Right, 8), (19, 0));
-        assert_eq!(turn_dial(19, Direction::Left, 19), (0, 1));
-        assert_eq!(turn_dial(5, Direction::Left, 5), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Right, 5), (5, 0));
+        assert_eq!(turn_dial(100, 11, Direction::Right, 8), (19, 0));
+        assert_eq!(turn_dial(100, 19, Direction::Left, 19), (0, 1));
+        assert_eq!(turn_dial(100, 5, Direction::Left, 5), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Right, 5), (5, 0));
 
         // Overflow x 1
-        assert_eq!(turn_dial(5, Direction::Left, 10), (95, 1));
-        assert_eq!(turn_dial(95, Direction::Right, 5), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Left, 5), (95, 0));
-        assert_eq!(turn_dial(0, Direction::Left, 100), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Right, 100), (0, 1));
+        assert_eq!(turn_dial(100, 5, Direction::Left, 10), (95, 1));
+        assert_eq!(turn_dial(100, 95, Direction::Right, 5), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 5), (95, 0));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 100), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Right, 100), (0, 1));
 
         // Multiple overflows
-        assert_eq!(turn_dial(50, Direction::Right, 200), (50, 2));
-        assert_eq!(turn_dial(50, Direction::Left, 201), (49, 2));
-        assert_eq!(turn_dial(50, Direction::Right, 150), (0, 2));
-        assert_eq!(turn_dial(50, Direction::Left, 150), (0, 2));
-        assert_eq!(turn_dial(0, Direction::Right, 200), (0, 2));
-        assert_eq!(turn_dial(0, Direction::Left, 200), (0, 2));
-        assert_eq!(turn_dial(0, Direction::Left, 150), (50, 1));
-        assert_eq!(turn_dial(50, Direction::Right, 1000), (50, 10));
+        assert_eq!(turn_dial(100, 50, Direction::Right, 200), (50, 2));
+        assert_eq!(turn_dial(100, 50, Direction::Left, 201), (49, 2));
+        assert_eq!(turn_dial(100, 50, Direction::Right, 150), (0, 2));
+        assert_eq!(turn_dial(100, 50, Direction::Left, 150), (0, 2));
+        assert_eq!(turn_dial(100, 0, Direction::Right, 200), (0, 2));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 200), (0, 2));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 150), (50, 1));
+        assert_eq!(turn_dial(100, 50, Direction::Right, 1000), (50, 10));
 
         // Example input
-        assert_eq!(turn_dial(50, Direction::Left, 68), (82, 1));
-        assert_eq!(turn_dial(82, Direction::Left, 30), (52, 0));
-        assert_eq!(turn_dial(52, Direction::Right, 48), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Left, 5), (95, 0));
-        assert_eq!(turn_dial(95, Direction::Right, 60), (55, 1));
-        assert_eq!(turn_dial(55, Direction::Left, 55), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Left, 1), (99, 0));
-        assert_eq!(turn_dial(99, Direction::Left, 99), (0, 1));
-        assert_eq!(turn_dial(0, Direction::Right, 14), (14, 0));
-        assert_eq!(turn_dial(14, Direction::Left, 82), (32, 1));
+        assert_eq!(turn_dial(100, 50, Direction::Left, 68), (82, 1));
+        assert_eq!(turn_dial(100, 82, Direction::Left, 30), (52, 0));
+        assert_eq!(turn_dial(100, 52, Direction::Right, 48), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 5), (95, 0));
+        assert_eq!(turn_dial(100, 95, Direction::Right, 60), (55, 1));
+        assert_eq!(turn_dial(100, 55, Direction::Left, 55), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Left, 1), (99, 0));
+        assert_eq!(turn_dial(100, 99, Direction::Left, 99), (0, 1));
+        assert_eq!(turn_dial(100, 0, Direction::Right, 14), (14, 0));
+        assert_eq!(turn_dial(100, 14, Direction::Left, 82), (32, 1));
+    }
+
+    #[test]
+    fn test_dial_turn_custom_length() {
+        // Same turns wrap around a length-7 dial but not a length-100 one
+        assert_eq!(turn_dial(7, 3, Direction::Right, 10), (6, 1));
+        assert_eq!(turn_dial(100, 3, Direction::Right, 10), (13, 0));
+        assert_eq!(turn_dial(7, 3, Direction::Left, 10), (0, 2));
+        assert_eq!(turn_dial(100, 3, Direction::Left, 10), (93, 1));
+
+        // Starting at `0` doesn't count, landing on `0` does
+        assert_eq!(turn_dial(7, 0, Direction::Right, 14), (0, 2));
+        assert_eq!(turn_dial(7, 0, Direction::Left, 7), (0, 1));
+        assert_eq!(turn_dial(7, 0, Direction::Left, 3), (4, 0));
+        assert_eq!(turn_dial(7, 5, Direction::Left, 5), (0, 1));
+
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let dial = Dial {
+            length: 7,
+            position: 0,
+        };
+        // Part One checks the position before each rotation, so the starting `0` is counted
+        assert_eq!(solve_day01_with_dial(input, Part::One, dial), Ok(2));
+        assert_eq!(solve_day01_with_dial(input, Part::Two, dial), Ok(64));
+        let dial = Dial {
+            length: 7,
+            position: 3,
+        };
+        assert_eq!(solve_day01_with_dial(input, Part::One, dial), Ok(2));
+        assert_eq!(solve_day01_with_dial(input, Part::Two, dial), Ok(67));
+    }
+
+    #[test]
+    fn test_position_history() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        assert_eq!(
+            position_history(input),
+            Ok(vec![82, 52, 0, 95, 55, 0, 99, 0, 14, 32])
+        );
+
+        assert_eq!(position_history(""), Ok(vec![]));
+        assert_eq!(
+            position_history("L68\nX30"),
+            Err(SolverError::InvalidInput("X30".into()))
+        );
     }
 
     #[test]
@@ -1,6 +1,10 @@
-use std::{fs, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
-use advent_of_code_2025::parse::parse_u64_number_range;
+use advent_of_code_2025::{
+    digits::{has_repeating_block, repeating_unit},
+    input::read_input,
+    parse::parse_u64_number_range,
+};
 use anyhow::Result;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,52 +27,62 @@ fn parse_input(input: &str) -> Vec<RangeInclusive<u64>> {
 /// Numbers of odd number of digits is always valid because it's impossible to split an
 /// odd-digit number equally in half.
 fn is_invalid_part_one(number: u64) -> bool {
+    split_halves(number).is_some_and(|(upper_half, lower_half)| upper_half == lower_half)
+}
+
+/// Splits the digits of a number into `(upper_half, lower_half)`, or `None` if it has an odd number
+/// of digits.
+///
+/// e.g. `123456` -> `(123, 456)`, `1001` -> `(10, 1)`
+fn split_halves(number: u64) -> Option<(u64, u64)> {
     let num_digits = number.ilog10() + 1;
     if num_digits % 2 == 1 {
-        false
+        None
     } else {
-        let upper_half = number / 10_u64.pow(num_digits / 2);
-        let lower_half = number % 10_u64.pow(num_digits / 2);
-        upper_half == lower_half
+        let divisor = 10_u64.pow(num_digits / 2);
+        Some((number / divisor, number % divisor))
     }
 }
 
 /// Part Two - Invalid if some digit sequence repeats at least twice (e.g. `123123123`: `123`x3).
 ///
-/// Algorithm: Start from left-most digit, gradually increase the length of the string to search.
-///
-/// Example: `123123`:
-///
-/// ```txt
-/// Search '1' from '123123123'
-///   Search '1' in '2', match = false
-/// Search '12' from '123123123'
-///   Skip search '12' in '3123123' since length of remaining substr not divisible by 2
-/// Search '123' from '123123123'
-///   Search '123' in '123', match = true
-///   Search '123' in '123', match = true
-/// ```
+/// See [has_repeating_block] for the algorithm.
 fn is_invalid_part_two(number: u64) -> bool {
     if number / 10 == 0 {
         return false; // Single digit always valid
     }
 
+    has_repeating_block(&number.to_string())
+}
+
+/// Finds the largest `k` such that the number is some digit sequence repeated `k` times.
+///
+/// e.g. `111111` = `1`x6 -> `6`, `123123` = `123`x2 -> `2`, `1234` -> `1`
+#[allow(dead_code)]
+fn max_repeat_factor(number: u64) -> usize {
     let number_str = number.to_string();
-    let num_digits = number_str.len();
 
-    (1..=num_digits.div_ceil(2)).any(|pattern_len| {
-        let rest_len = num_digits - pattern_len;
-        if !rest_len.is_multiple_of(pattern_len) {
-            return false;
-        }
+    // The shortest repeated pattern gives the most repeats
+    repeating_unit(&number_str).map_or(1, |unit| number_str.len() / unit.len())
+}
 
-        let pattern = &number_str[..pattern_len];
-        (0..rest_len / pattern_len).all(|round| {
-            let start_index = pattern_len + pattern_len * round;
-            let sub_str = &number_str[start_index..start_index + pattern_len];
-            pattern == sub_str
-        })
-    })
+/// Explains why the ID is invalid under the rules of `part`, or `None` if it's valid.
+///
+/// e.g. `explain_invalid(6464, Part::One)` -> `Some("6464 = '64' repeated 2 times")`
+#[allow(dead_code)]
+fn explain_invalid(number: u64, part: Part) -> Option<String> {
+    let (unit, repeats) = match part {
+        Part::One => {
+            let (upper_half, lower_half) = split_halves(number)?;
+            (upper_half == lower_half).then(|| (upper_half.to_string(), 2))?
+        }
+        Part::Two => {
+            let number_str = number.to_string();
+            let unit = repeating_unit(&number_str)?;
+            (unit.to_string(), number_str.len() / unit.len())
+        }
+    };
+    Some(format!("{number} = '{unit}' repeated {repeats} times"))
 }
 
 fn find_invalid_ids(range: RangeInclusive<u64>, part: Part) -> Vec<u64> {
@@ -79,18 +93,49 @@ fn find_invalid_ids(range: RangeInclusive<u64>, part: Part) -> Vec<u64> {
     range.filter(|&number| is_invalid(number)).collect()
 }
 
+/// Number of IDs scanned by each task in [find_invalid_ids_parallel].
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK_SIZE: u64 = 100_000;
+
+/// Same as [find_invalid_ids], but splits the range into chunks and scans them in parallel, which
+/// is much faster for wide ranges.
+#[cfg(feature = "parallel")]
+fn find_invalid_ids_parallel(range: RangeInclusive<u64>, part: Part) -> Vec<u64> {
+    use rayon::prelude::*;
+
+    let (start, end) = range.into_inner();
+    if start > end {
+        return vec![];
+    }
+    let chunk_count = (end - start) / PARALLEL_CHUNK_SIZE + 1;
+
+    (0..chunk_count)
+        .into_par_iter()
+        .flat_map_iter(|chunk| {
+            let chunk_start = start + chunk * PARALLEL_CHUNK_SIZE;
+            let chunk_end = chunk_start.saturating_add(PARALLEL_CHUNK_SIZE - 1).min(end);
+            find_invalid_ids(chunk_start..=chunk_end, part)
+        })
+        .collect()
+}
+
 /// Day 2: Gift Shop
 ///
 /// - Part One: ID is "invalid" if some digit sequence repeats twice (e.g. `6464` - `64`x2).
 /// - Part Two: ID is "invalid" if some digit sequence repeats at least twice (e.g. `123123123` = `123`x3)
+///
+/// Ranges are scanned in parallel if the `parallel` feature is enabled.
 fn solve_day02(ranges: &[RangeInclusive<u64>], part: Part) -> u64 {
+    #[cfg(feature = "parallel")]
+    let find_invalid_ids = find_invalid_ids_parallel;
+
     ranges.iter().fold(0, |sum, range| {
         sum + find_invalid_ids(range.clone(), part).iter().sum::<u64>()
     })
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day02.txt")?;
+    let input = read_input("puzzle_inputs/day02.txt")?;
     let input = input.trim();
     let ranges = parse_input(input);
 
@@ -147,6 +192,54 @@ mod tests {
         assert!(!is_invalid_part_two(12341234123));
     }
 
+    #[test]
+    fn test_max_repeat_factor() {
+        assert_eq!(max_repeat_factor(111111), 6);
+        assert_eq!(max_repeat_factor(123123), 2);
+        assert_eq!(max_repeat_factor(1234), 1);
+        assert_eq!(max_repeat_factor(121212121212), 6);
+        assert_eq!(max_repeat_factor(935935935935), 4);
+        assert_eq!(max_repeat_factor(1001), 1);
+        assert_eq!(max_repeat_factor(7), 1);
+        assert_eq!(max_repeat_factor(0), 1);
+    }
+
+    #[test]
+    fn test_split_halves() {
+        assert_eq!(split_halves(123456), Some((123, 456)));
+        assert_eq!(split_halves(1001), Some((10, 1)));
+        assert_eq!(split_halves(12345), None);
+    }
+
+    #[test]
+    fn test_explain_invalid() {
+        assert_eq!(
+            explain_invalid(6464, Part::One),
+            Some("6464 = '64' repeated 2 times".into())
+        );
+        assert_eq!(
+            explain_invalid(1188511885, Part::One),
+            Some("1188511885 = '11885' repeated 2 times".into())
+        );
+        // Repeated 3 times is only invalid in Part Two
+        assert_eq!(explain_invalid(123123123, Part::One), None);
+        assert_eq!(
+            explain_invalid(123123123, Part::Two),
+            Some("123123123 = '123' repeated 3 times".into())
+        );
+        // Shortest unit is used
+        assert_eq!(
+            explain_invalid(222222, Part::Two),
+            Some("222222 = '2' repeated 6 times".into())
+        );
+
+        for part in [Part::One, Part::Two] {
+            assert_eq!(explain_invalid(1234, part), None);
+            assert_eq!(explain_invalid(1001, part), None);
+            assert_eq!(explain_invalid(7, part), None);
+        }
+    }
+
     #[test]
     fn test_find_invalid_ids_part_one() {
         // Puzzle example
@@ -197,6 +290,19 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_find_invalid_ids_parallel() {
+        for part in [Part::One, Part::Two] {
+            for range in [1..=1_000_000, 998..=1012, 95..=115] {
+                assert_eq!(
+                    find_invalid_ids_parallel(range.clone(), part),
+                    find_invalid_ids(range, part)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_solve_day02() {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,\
@@ -1,6 +1,4 @@
-use std::fs;
-
-use advent_of_code_2025::Part;
+use advent_of_code_2025::{Part, input::read_input};
 use anyhow::Result;
 
 #[inline]
@@ -78,7 +76,7 @@ fn solve_day03(input: &str, part: Part) -> u64 {
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day03.txt")?;
+    let input = read_input("puzzle_inputs/day03.txt")?;
     let input = input.trim();
 
     let part_1_solution = solve_day03(input, Part::One);
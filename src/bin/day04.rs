@@ -1,6 +1,8 @@
-use std::{fmt::Display, fs, vec};
+use std::{fmt::Display, vec};
 
-use advent_of_code_2025::{Part, grid::parse_string_to_grid};
+use advent_of_code_2025::{
+    Part, grid::parse_string_to_grid, input::read_input, iter::iterate_to_fixpoint,
+};
 use anyhow::{Error, Result};
 use grid::*;
 use itertools::iproduct;
@@ -36,20 +38,68 @@ impl TryFrom<char> for Cell {
 /// to this number.
 const ACCESSIBLE_ROLL_MAX_ADJACENCY: usize = 3;
 
-/// Converts a cell grid to a string. Used for debugging purposes.
-#[allow(dead_code)]
-fn grid_to_string(grid: &Grid<Cell>) -> String {
-    grid.iter_rows()
-        .map(|row| row.map(|cell| cell.to_string()).collect::<String>())
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-/// Counts the number of paper rolls adjacent to a cell of coordinates `(row, col)`.
-fn count_adjacent_rolls(grid: &Grid<Cell>, row: usize, col: usize) -> usize {
-    iproduct!(-1..=1, -1..=1)
-        .filter(|&(dy, dx)| (dy, dx) != (0, 0))
-        .map(|(dy, dx)| {
+/// Which cells around a cell count as adjacent.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Neighborhood {
+    /// The 8 surrounding cells, including diagonals. This matches the puzzle.
+    #[default]
+    Moore,
+    /// The 4 orthogonally adjacent cells.
+    #[allow(dead_code)]
+    VonNeumann,
+}
+
+impl Neighborhood {
+    /// `(dy, dx)` offsets of the adjacent cells.
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Neighborhood::VonNeumann => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+        }
+    }
+}
+
+/// Rules deciding whether a paper roll is "accessible".
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AccessibilityRules {
+    /// Which cells count as adjacent to a roll.
+    neighborhood: Neighborhood,
+    /// A roll is accessible if the number of rolls adjacent to it is smaller than or equal to this
+    /// number.
+    max_adjacency: usize,
+}
+
+impl Default for AccessibilityRules {
+    /// Rules of the puzzle.
+    fn default() -> Self {
+        Self {
+            neighborhood: Neighborhood::default(),
+            max_adjacency: ACCESSIBLE_ROLL_MAX_ADJACENCY,
+        }
+    }
+}
+
+/// Counts the number of paper rolls adjacent to a cell of coordinates `(row, col)`, where the
+/// adjacent cells are given by `neighborhood`.
+fn count_adjacent_rolls(
+    grid: &Grid<Cell>,
+    row: usize,
+    col: usize,
+    neighborhood: Neighborhood,
+) -> usize {
+    neighborhood
+        .offsets()
+        .iter()
+        .map(|&(dy, dx)| {
             // Ignore out-of-bounds cell (i.e. index < 0)
             let Some(new_row) = row.checked_add_signed(dy) else {
                 return false;
@@ -63,53 +113,146 @@ fn count_adjacent_rolls(grid: &Grid<Cell>, row: usize, col: usize) -> usize {
         .count()
 }
 
+/// How accessible paper rolls are removed within a single round.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum RemovalMode {
+    /// Finds all accessible rolls against the grid at the start of the round, then removes them all
+    /// at once. This matches the puzzle.
+    #[default]
+    Simultaneous,
+    /// Visits the rolls from top to bottom, left to right, and removes each accessible roll
+    /// immediately. A removal can make later rolls in the same round accessible, so each round may
+    /// remove more rolls than [RemovalMode::Simultaneous].
+    #[allow(dead_code)]
+    Sequential,
+}
+
 /// Keeps removing "accessible" paper rolls from a grid until there are no further accessible paper
 /// rolls can be removed or it hits the `max_rounds` limit.
 ///
 /// Pass `None` to `max_rounds` to make it infinitely loop until all accessible paper rolls are removed.
 ///
 /// Returns a list of number of paper rolls removed in each iteration.
-fn remove_accessible_rolls(initial_grid: &Grid<Cell>, max_rounds: Option<usize>) -> Vec<usize> {
-    let grid: &mut Grid<Cell> = &mut initial_grid.clone();
+fn remove_accessible_rolls(
+    initial_grid: &Grid<Cell>,
+    max_rounds: Option<usize>,
+    mode: RemovalMode,
+    rules: AccessibilityRules,
+) -> Vec<usize> {
+    let mut grid = initial_grid.clone();
     let mut round = 0_usize;
     let mut removed_rolls_counts: Vec<usize> = vec![];
 
     while max_rounds.is_none_or(|max| round < max) {
-        // println!("{}\n\n", grid_to_string(grid));
-
-        let accessible_rolls_coords: Vec<(usize, usize)> = grid
-            .indexed_iter()
-            .filter_map(|((row, col), &cell)| {
-                let is_accessible = cell == Cell::Roll
-                    && count_adjacent_rolls(grid, row, col) <= ACCESSIBLE_ROLL_MAX_ADJACENCY;
-                if is_accessible {
-                    Some((row, col))
-                } else {
-                    None
-                }
-            })
-            .collect();
+        // advent_of_code_2025::grid::print_grid(&grid);
 
-        let removed_rolls_count = accessible_rolls_coords.len();
+        let removed_rolls_count = remove_accessible_rolls_once(&mut grid, mode, rules);
         removed_rolls_counts.push(removed_rolls_count);
 
         if removed_rolls_count == 0 {
             break;
         }
 
-        // Remove the accessible rolls
-        accessible_rolls_coords.iter().for_each(|&(row, col)| {
-            if let Some(cell) = grid.get_mut(row, col) {
-                *cell = Cell::Empty;
-            }
-        });
-
         round += 1;
     }
 
     removed_rolls_counts
 }
 
+/// Removes the accessible paper rolls for a single round, and returns the number of rolls removed.
+fn remove_accessible_rolls_once(
+    grid: &mut Grid<Cell>,
+    mode: RemovalMode,
+    rules: AccessibilityRules,
+) -> usize {
+    let is_accessible = |grid: &Grid<Cell>, row: usize, col: usize| {
+        grid[(row, col)] == Cell::Roll
+            && count_adjacent_rolls(grid, row, col, rules.neighborhood) <= rules.max_adjacency
+    };
+
+    match mode {
+        RemovalMode::Simultaneous => {
+            let accessible_rolls_coords: Vec<(usize, usize)> = grid
+                .indexed_iter()
+                .filter_map(|((row, col), _)| is_accessible(grid, row, col).then_some((row, col)))
+                .collect();
+
+            // Remove the accessible rolls
+            accessible_rolls_coords.iter().for_each(|&(row, col)| {
+                if let Some(cell) = grid.get_mut(row, col) {
+                    *cell = Cell::Empty;
+                }
+            });
+
+            accessible_rolls_coords.len()
+        }
+        RemovalMode::Sequential => {
+            let mut removed_rolls_count = 0;
+            for (row, col) in iproduct!(0..grid.rows(), 0..grid.cols()) {
+                if is_accessible(grid, row, col) {
+                    grid[(row, col)] = Cell::Empty;
+                    removed_rolls_count += 1;
+                }
+            }
+            removed_rolls_count
+        }
+    }
+}
+
+/// Returns the grid after all accessible paper rolls are removed, i.e. the remaining rolls can never
+/// be removed.
+#[allow(dead_code)]
+fn stable_grid(grid: &Grid<Cell>) -> Grid<Cell> {
+    let remove_once = |grid: &Grid<Cell>| {
+        let mut grid = grid.clone();
+        remove_accessible_rolls_once(
+            &mut grid,
+            RemovalMode::default(),
+            AccessibilityRules::default(),
+        );
+        grid
+    };
+    iterate_to_fixpoint(grid.clone(), remove_once, None).0
+}
+
+/// Finds the `(row, col)` of the paper rolls that can never be removed, i.e. the rolls remaining in
+/// the [stable_grid]. Coordinates are in row-major order.
+#[allow(dead_code)]
+fn permanent_rolls(grid: &Grid<Cell>) -> Vec<(usize, usize)> {
+    stable_grid(grid)
+        .indexed_iter()
+        .filter(|(_, cell)| **cell == Cell::Roll)
+        .map(|(coords, _)| coords)
+        .collect()
+}
+
+/// Maps every paper roll to the 1-based round it is removed in, using the puzzle's removal rules.
+/// Permanent rolls (see [permanent_rolls]) and empty cells map to `None`.
+#[allow(dead_code)]
+fn removal_round_map(grid: &Grid<Cell>) -> Grid<Option<usize>> {
+    let mut rounds: Grid<Option<usize>> = Grid::new(grid.rows(), grid.cols());
+    let mut grid = grid.clone();
+
+    for round in 1.. {
+        let previous_grid = grid.clone();
+        let removed_rolls_count = remove_accessible_rolls_once(
+            &mut grid,
+            RemovalMode::default(),
+            AccessibilityRules::default(),
+        );
+        if removed_rolls_count == 0 {
+            break;
+        }
+
+        previous_grid
+            .indexed_iter()
+            .filter(|&(coords, cell)| *cell == Cell::Roll && grid[coords] == Cell::Empty)
+            .for_each(|(coords, _)| rounds[coords] = Some(round));
+    }
+
+    rounds
+}
+
 /// Day 4: Printing Department
 ///
 /// - Part One: Find the total number of "accessible" paper rolls from the grid
@@ -120,11 +263,18 @@ fn solve_day04(grid: &Grid<Cell>, part: Part) -> usize {
         Part::One => Some(1),
         Part::Two => None,
     };
-    remove_accessible_rolls(grid, max_rounds).iter().sum()
+    remove_accessible_rolls(
+        grid,
+        max_rounds,
+        RemovalMode::default(),
+        AccessibilityRules::default(),
+    )
+    .iter()
+    .sum()
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day04.txt")?;
+    let input = read_input("puzzle_inputs/day04.txt")?;
     let input = input.trim();
     let grid = parse_string_to_grid(input, Cell::try_from)?;
 
@@ -140,6 +290,31 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    /// Puzzle example.
+    const EXAMPLE_INPUT: &str = r"
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    /// Small grid with a mix of accessible and blocked rolls.
+    const SMALL_INPUT: &str = "..@@\n@@@@\n@.@.\n@@@@";
+
+    fn example_grid() -> Grid<Cell> {
+        parse_string_to_grid(EXAMPLE_INPUT.trim(), Cell::try_from).unwrap()
+    }
+
+    fn small_grid() -> Grid<Cell> {
+        parse_string_to_grid(SMALL_INPUT, Cell::try_from).unwrap()
+    }
+
     #[test]
     fn test_parse_input_to_grid() {
         let input = "..@.\n@@.@";
@@ -156,62 +331,206 @@ mod tests {
 
     #[test]
     fn test_count_adjacent_rolls() {
-        let grid = grid![
-            [Cell::Empty, Cell::Empty, Cell::Roll, Cell::Roll]
-            [Cell::Roll, Cell::Roll, Cell::Roll, Cell::Roll]
-            [Cell::Roll, Cell::Empty, Cell::Roll, Cell::Empty]
-            [Cell::Roll, Cell::Roll, Cell::Roll, Cell::Roll]
-        ];
-        assert_eq!(count_adjacent_rolls(&grid, 0, 0), 2);
-        assert_eq!(count_adjacent_rolls(&grid, 0, 1), 4);
-        assert_eq!(count_adjacent_rolls(&grid, 0, 3), 3);
-        assert_eq!(count_adjacent_rolls(&grid, 2, 1), 8);
-        assert_eq!(count_adjacent_rolls(&grid, 3, 0), 2);
-        assert_eq!(count_adjacent_rolls(&grid, 3, 3), 2);
+        let grid = small_grid();
+        assert_eq!(count_adjacent_rolls(&grid, 0, 0, Neighborhood::Moore), 2);
+        assert_eq!(count_adjacent_rolls(&grid, 0, 1, Neighborhood::Moore), 4);
+        assert_eq!(count_adjacent_rolls(&grid, 0, 3, Neighborhood::Moore), 3);
+        assert_eq!(count_adjacent_rolls(&grid, 2, 1, Neighborhood::Moore), 8);
+        assert_eq!(count_adjacent_rolls(&grid, 3, 0, Neighborhood::Moore), 2);
+        assert_eq!(count_adjacent_rolls(&grid, 3, 3, Neighborhood::Moore), 2);
     }
 
     #[test]
     fn test_remove_accessible_rolls() {
-        let grid = grid![
-            [Cell::Empty, Cell::Empty, Cell::Roll, Cell::Roll]
-            [Cell::Roll, Cell::Roll, Cell::Roll, Cell::Roll]
-            [Cell::Roll, Cell::Empty, Cell::Roll, Cell::Empty]
-            [Cell::Roll, Cell::Roll, Cell::Roll, Cell::Roll]
-        ];
-        assert_eq!(remove_accessible_rolls(&grid, None), vec![5, 4, 3, 0]);
-        assert_eq!(remove_accessible_rolls(&grid, Some(5)), vec![5, 4, 3, 0]);
-        assert_eq!(remove_accessible_rolls(&grid, Some(1)), vec![5]);
+        let grid = small_grid();
+        assert_eq!(
+            remove_accessible_rolls(
+                &grid,
+                None,
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![5, 4, 3, 0]
+        );
+        assert_eq!(
+            remove_accessible_rolls(
+                &grid,
+                Some(5),
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![5, 4, 3, 0]
+        );
+        assert_eq!(
+            remove_accessible_rolls(
+                &grid,
+                Some(1),
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![5]
+        );
 
         let all_empty_grid = grid![
             [Cell::Empty, Cell::Empty, Cell::Empty]
             [Cell::Empty, Cell::Empty, Cell::Empty]
             [Cell::Empty, Cell::Empty, Cell::Empty]
         ];
-        assert_eq!(remove_accessible_rolls(&all_empty_grid, None), vec![0]);
-        assert_eq!(remove_accessible_rolls(&all_empty_grid, Some(5)), vec![0]);
+        assert_eq!(
+            remove_accessible_rolls(
+                &all_empty_grid,
+                None,
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![0]
+        );
+        assert_eq!(
+            remove_accessible_rolls(
+                &all_empty_grid,
+                Some(5),
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn test_count_adjacent_rolls_von_neumann() {
+        let grid = small_grid();
+        let count = |row, col| count_adjacent_rolls(&grid, row, col, Neighborhood::VonNeumann);
+        assert_eq!(count(0, 0), 1);
+        assert_eq!(count(0, 1), 2);
+        assert_eq!(count(1, 2), 4);
+        assert_eq!(count(2, 1), 4);
+        assert_eq!(count(3, 3), 1);
+    }
+
+    #[test]
+    fn test_remove_accessible_rolls_with_rules() {
+        let grid = small_grid();
+        let remove = |neighborhood, max_adjacency| {
+            let rules = AccessibilityRules {
+                neighborhood,
+                max_adjacency,
+            };
+            remove_accessible_rolls(&grid, None, RemovalMode::Simultaneous, rules)
+        };
+
+        assert_eq!(remove(Neighborhood::Moore, 3), vec![5, 4, 3, 0]);
+        // Only (1, 2) has 4 orthogonal neighbors, so everything else is removed in the first round
+        assert_eq!(remove(Neighborhood::VonNeumann, 3), vec![11, 1, 0]);
+
+        // Stricter thresholds
+        assert_eq!(remove(Neighborhood::Moore, 2), vec![3, 2, 1, 0]);
+        assert_eq!(remove(Neighborhood::VonNeumann, 1), vec![1, 0]);
+        assert_eq!(remove(Neighborhood::VonNeumann, 0), vec![0]);
+    }
+
+    #[test]
+    fn test_remove_accessible_rolls_sequential() {
+        let grid = small_grid();
+        // Same grid as `test_remove_accessible_rolls`, which takes 3 rounds in simultaneous mode.
+        // Removing a roll immediately makes its neighbors accessible in the same round, so fewer
+        // rounds are needed to remove the same total number of rolls
+        assert_eq!(
+            remove_accessible_rolls(
+                &grid,
+                None,
+                RemovalMode::Sequential,
+                AccessibilityRules::default()
+            ),
+            vec![8, 4, 0]
+        );
+        assert_eq!(
+            remove_accessible_rolls(
+                &grid,
+                Some(1),
+                RemovalMode::Sequential,
+                AccessibilityRules::default()
+            ),
+            vec![8]
+        );
     }
 
     #[test]
     fn test_solve_day04() {
+        let grid = example_grid();
+
+        remove_accessible_rolls(
+            &grid,
+            None,
+            RemovalMode::Simultaneous,
+            AccessibilityRules::default(),
+        );
+
+        assert_eq!(solve_day04(&grid, Part::One), 13);
+        assert_eq!(solve_day04(&grid, Part::Two), 43);
+    }
+
+    #[test]
+    fn test_stable_grid() {
+        let grid = example_grid();
+        let count_rolls =
+            |grid: &Grid<Cell>| grid.iter().filter(|&&cell| cell == Cell::Roll).count();
+
+        let stable = stable_grid(&grid);
+        assert_eq!(count_rolls(&stable), count_rolls(&grid) - 43);
+        assert_eq!(
+            remove_accessible_rolls(
+                &stable,
+                None,
+                RemovalMode::Simultaneous,
+                AccessibilityRules::default()
+            ),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn test_permanent_rolls() {
+        // A dense 5x5 block, where only the corners of the block and the scattered rolls are accessible
         let input = r"
-..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
+@......
+.@@@@@.
+.@@@@@.
+.@@@@@.
+.@@@@@.
+.@@@@@.
+@.....@
 "
         .trim();
-
         let grid = parse_string_to_grid(input, Cell::try_from).unwrap();
+        let block_corners = [(1, 1), (1, 5), (5, 1), (5, 5)];
+        let expected: Vec<(usize, usize)> = iproduct!(1..=5, 1..=5)
+            .filter(|coords| !block_corners.contains(coords))
+            .collect();
+        assert_eq!(permanent_rolls(&grid), expected);
 
-        remove_accessible_rolls(&grid, None);
+        // All rolls are removed eventually
+        let grid = parse_string_to_grid("@@@\n@@@\n@@@", Cell::try_from).unwrap();
+        assert_eq!(permanent_rolls(&grid), vec![]);
+    }
 
-        assert_eq!(solve_day04(&grid, Part::One), 13);
-        assert_eq!(solve_day04(&grid, Part::Two), 43);
+    #[test]
+    fn test_removal_round_map() {
+        let grid = example_grid();
+        let rounds = removal_round_map(&grid);
+
+        assert_eq!(rounds[(0, 2)], Some(1));
+        assert_eq!(rounds[(0, 7)], Some(2));
+        assert_eq!(rounds[(0, 0)], None); // empty cell
+        assert_eq!(rounds[(4, 4)], None); // permanent roll
+
+        let count_round = |round: usize| rounds.iter().filter(|&&r| r == Some(round)).count();
+        assert_eq!(count_round(1), 13);
+        assert_eq!(rounds.iter().flatten().count(), 43);
+        let permanent: Vec<(usize, usize)> = rounds
+            .indexed_iter()
+            .filter(|&(coords, round)| round.is_none() && grid[coords] == Cell::Roll)
+            .map(|(coords, _)| coords)
+            .collect();
+        assert_eq!(permanent, permanent_rolls(&grid));
     }
 }
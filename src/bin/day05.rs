@@ -1,6 +1,8 @@
-use std::{fs, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
-use advent_of_code_2025::{Part, parse::parse_u64_number_range};
+use advent_of_code_2025::{
+    Part, input::read_input, iter::unordered_pairs, parse::parse_u64_number_range,
+};
 use anyhow::Result;
 use itertools::Itertools;
 
@@ -62,20 +64,131 @@ fn parse_input_to_database(input: &str) -> Database {
 ///
 /// Visualization: https://youtu.be/hG9QDwiE28w
 fn sort_and_merge_ranges(input: &[RangeInclusive<u64>]) -> Vec<RangeInclusive<u64>> {
-    input
+    merge_ranges_by(input, |last_range, range| range.start() <= last_range.end())
+        .into_iter()
+        .map(|(range, _)| range)
+        .collect()
+}
+
+/// Sorts the ranges by ascending order of the range's start, and merges each range into the last
+/// merged range if `should_merge(last_range, range)` is `true`.
+///
+/// Each merged range is paired with the indices of the input ranges that were combined into it (in
+/// ascending order of the range's start).
+fn merge_ranges_by(
+    ranges: &[RangeInclusive<u64>],
+    should_merge: impl Fn(&RangeInclusive<u64>, &RangeInclusive<u64>) -> bool,
+) -> Vec<(RangeInclusive<u64>, Vec<usize>)> {
+    ranges
         .iter()
-        .sorted_by_key(|range| range.start())
-        .fold(vec![], |mut output, range| {
+        .enumerate()
+        .sorted_by_key(|(_, range)| range.start())
+        .fold(vec![], |mut output, (idx, range)| {
             match output.last_mut() {
-                Some(last_range) if range.start() <= last_range.end() => {
+                Some((last_range, indexes)) if should_merge(last_range, range) => {
                     *last_range = *last_range.start()..=*range.end().max(last_range.end());
+                    indexes.push(idx);
                 }
-                _ => output.push(range.clone()),
+                _ => output.push((range.clone(), vec![idx])),
             }
             output
         })
 }
 
+/// Same as [sort_and_merge_ranges], but also merges ranges that are adjacent without overlapping,
+/// i.e. this range's start <= last range's end + 1.
+///
+/// # Example
+///
+/// `1-5` and `6-10` share no ID, so [sort_and_merge_ranges] keeps them separate, but this function
+/// merges them into `1-10`.
+#[allow(dead_code)]
+fn merge_ranges_adjacent(ranges: &[RangeInclusive<u64>]) -> Vec<RangeInclusive<u64>> {
+    merge_ranges_by(ranges, |last_range, range| {
+        *range.start() <= last_range.end().saturating_add(1)
+    })
+    .into_iter()
+    .map(|(range, _)| range)
+    .collect()
+}
+
+/// Same as [sort_and_merge_ranges], but each merged range is paired with the indices of the input
+/// ranges that were combined into it (in ascending order of the range's start).
+///
+/// # Example
+///
+/// `merge_ranges_with_provenance(&[10..=14, 3..=5, 12..=18])` returns
+/// `vec![(3..=5, vec![1]), (10..=18, vec![0, 2])]`.
+#[allow(dead_code)]
+fn merge_ranges_with_provenance(
+    ranges: &[RangeInclusive<u64>],
+) -> Vec<(RangeInclusive<u64>, Vec<usize>)> {
+    merge_ranges_by(ranges, |last_range, range| {
+        range.start() <= last_range.end()
+    })
+}
+
+/// Finds how much the ranges overlap with each other before merging. Returns a tuple of
+/// `(overlapping_pairs, overlapping_length)`:
+///
+/// - `overlapping_pairs` - Number of pairs of ranges that share at least one ID
+/// - `overlapping_length` - Sum of the number of IDs shared by each overlapping pair. It's a `u128`
+///   since a single pair can share all `2^64` IDs.
+///
+/// # Example
+///
+/// `overlap_stats(&[10..=14, 12..=18, 16..=20])` returns `(2, 6)`, since `10..=14` and `12..=18`
+/// share `12..=14`, while `12..=18` and `16..=20` share `16..=18`.
+#[allow(dead_code)]
+fn overlap_stats(ranges: &[RangeInclusive<u64>]) -> (usize, u128) {
+    unordered_pairs(ranges)
+        .filter_map(|(a, b)| {
+            let start = a.start().max(b.start());
+            let end = a.end().min(b.end());
+            (start <= end).then(|| u128::from(end - start) + 1)
+        })
+        .fold((0, 0), |(pairs, length), overlap| {
+            (pairs + 1, length + overlap)
+        })
+}
+
+/// Finds the ID covered by the most ranges before merging, and the number of ranges covering it. If
+/// several IDs share the maximum coverage, the smallest one is returned.
+///
+/// Inverted ranges (e.g. `5..=3`) cover no ID, so they're skipped. Returns `None` if there are no
+/// ranges left.
+///
+/// It sweeps through the range endpoints in ascending order, where a range starts covering at its
+/// start and stops covering after its end. At the same position, starts are processed before ends
+/// since the ranges are inclusive.
+///
+/// # Example
+///
+/// `max_coverage_id(&[10..=14, 12..=18, 16..=20])` returns `Some((12, 2))`.
+#[allow(dead_code)]
+fn max_coverage_id(ranges: &[RangeInclusive<u64>]) -> Option<(u64, usize)> {
+    // `(position, is_end)`, so starts are sorted before ends at the same position
+    let events = ranges
+        .iter()
+        .filter(|range| !range.is_empty())
+        .flat_map(|range| [(*range.start(), false), (*range.end(), true)])
+        .sorted_unstable();
+
+    let mut coverage = 0;
+    let mut max_coverage: Option<(u64, usize)> = None;
+    for (position, is_end) in events {
+        if is_end {
+            coverage -= 1;
+            continue;
+        }
+        coverage += 1;
+        if max_coverage.is_none_or(|(_, max)| coverage > max) {
+            max_coverage = Some((position, coverage));
+        }
+    }
+    max_coverage
+}
+
 fn optimize_database(database: Database) -> Database {
     let optimized_fresh_id_ranges = sort_and_merge_ranges(&database.fresh_id_ranges);
     Database::new(optimized_fresh_id_ranges, database.available_ids)
@@ -108,6 +221,18 @@ fn count_all_fresh_ids(optimized_database: &Database) -> u64 {
         .sum()
 }
 
+/// Finds the smallest fresh ID that is `>= from`, or `None` if all fresh IDs are smaller than `from`.
+///
+/// NOTE: The database should be optimized so that the `fresh_id_ranges` are sorted and merged,
+/// since the ranges are binary searched.
+#[allow(dead_code)]
+fn next_fresh_id(optimized_database: &Database, from: u64) -> Option<u64> {
+    let ranges = &optimized_database.fresh_id_ranges;
+    // Index of the first range that doesn't end before `from`
+    let idx = ranges.partition_point(|range| *range.end() < from);
+    ranges.get(idx).map(|range| from.max(*range.start()))
+}
+
 fn solve_day05(input: &str, part: Part) -> u64 {
     let raw_database = parse_input_to_database(input);
     let optimized_database = optimize_database(raw_database);
@@ -119,7 +244,7 @@ fn solve_day05(input: &str, part: Part) -> u64 {
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day05.txt")?;
+    let input = read_input("puzzle_inputs/day05.txt")?;
     let input = input.trim();
 
     let part_1_solution = solve_day05(input, Part::One);
@@ -169,6 +294,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_merge_ranges_adjacent() {
+        assert_eq!(merge_ranges_adjacent(&[]), vec![]);
+
+        // Adjacent ranges are only merged by the adjacent variant
+        assert_eq!(merge_ranges_adjacent(&[6..=10, 1..=5]), vec![1..=10]);
+        assert_eq!(sort_and_merge_ranges(&[6..=10, 1..=5]), vec![1..=5, 6..=10]);
+
+        // Gap of 1 ID is not merged
+        assert_eq!(merge_ranges_adjacent(&[1..=5, 7..=10]), vec![1..=5, 7..=10]);
+
+        assert_eq!(
+            merge_ranges_adjacent(&[1..=5, 7..=12, 6..=8, 19..=26, 12..=13, 21..=25, 14..=14]),
+            vec![1..=14, 19..=26]
+        );
+        assert_eq!(
+            merge_ranges_adjacent(&[u64::MAX..=u64::MAX, 0..=u64::MAX]),
+            vec![0..=u64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges_with_provenance() {
+        assert_eq!(merge_ranges_with_provenance(&[]), vec![]);
+
+        // Puzzle example
+        assert_eq!(
+            merge_ranges_with_provenance(&[3..=5, 10..=14, 16..=20, 12..=18]),
+            vec![(3..=5, vec![0]), (10..=20, vec![1, 3, 2])]
+        );
+
+        assert_eq!(
+            merge_ranges_with_provenance(&[1..=5, 7..=12, 6..=8, 19..=26, 12..=13, 21..=25]),
+            vec![
+                (1..=5, vec![0]),
+                (6..=13, vec![2, 1, 4]),
+                (19..=26, vec![3, 5])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_fresh_id() {
+        let database = optimize_database(Database::new(
+            vec![10..=14, 3..=5, 16..=20, 12..=18],
+            vec![],
+        ));
+
+        // Inside a range
+        assert_eq!(next_fresh_id(&database, 3), Some(3));
+        assert_eq!(next_fresh_id(&database, 4), Some(4));
+        assert_eq!(next_fresh_id(&database, 15), Some(15));
+        assert_eq!(next_fresh_id(&database, 20), Some(20));
+
+        // In a gap
+        assert_eq!(next_fresh_id(&database, 0), Some(3));
+        assert_eq!(next_fresh_id(&database, 6), Some(10));
+        assert_eq!(next_fresh_id(&database, 9), Some(10));
+
+        // Past all ranges
+        assert_eq!(next_fresh_id(&database, 21), None);
+        assert_eq!(next_fresh_id(&database, u64::MAX), None);
+
+        assert_eq!(next_fresh_id(&Database::new(vec![], vec![]), 0), None);
+    }
+
+    #[test]
+    fn test_overlap_stats() {
+        // Puzzle example: `10-14` & `12-18` share 3 IDs, `16-20` & `12-18` share 3 IDs
+        assert_eq!(overlap_stats(&[3..=5, 10..=14, 16..=20, 12..=18]), (2, 6));
+
+        // Touching at a single ID
+        assert_eq!(overlap_stats(&[1..=5, 5..=8]), (1, 1));
+        // Fully contained in another range
+        assert_eq!(overlap_stats(&[1..=10, 3..=4, 4..=6]), (3, 2 + 3 + 1));
+
+        assert_eq!(overlap_stats(&[1..=5, 6..=8]), (0, 0));
+        assert_eq!(overlap_stats(&[]), (0, 0));
+
+        // Shared lengths beyond `u64::MAX`
+        let full_length = u128::from(u64::MAX) + 1;
+        assert_eq!(
+            overlap_stats(&[0..=u64::MAX, 0..=u64::MAX]),
+            (1, full_length)
+        );
+        assert_eq!(
+            overlap_stats(&[0..=u64::MAX, 0..=u64::MAX, 0..=u64::MAX]),
+            (3, 3 * full_length)
+        );
+    }
+
+    #[test]
+    fn test_max_coverage_id() {
+        // Puzzle example: `12-14` and `16-18` are covered by 2 ranges
+        assert_eq!(
+            max_coverage_id(&[3..=5, 10..=14, 16..=20, 12..=18]),
+            Some((12, 2))
+        );
+
+        // Touching at a single ID
+        assert_eq!(max_coverage_id(&[1..=5, 5..=8]), Some((5, 2)));
+        assert_eq!(max_coverage_id(&[1..=5, 6..=8]), Some((1, 1)));
+        // Nested ranges
+        assert_eq!(max_coverage_id(&[1..=10, 3..=4, 4..=6]), Some((4, 3)));
+        assert_eq!(max_coverage_id(&[2..=2, 2..=2]), Some((2, 2)));
+        assert_eq!(max_coverage_id(&[0..=u64::MAX, 7..=u64::MAX]), Some((7, 2)));
+
+        assert_eq!(max_coverage_id(&[]), None);
+
+        // Inverted ranges are skipped
+        assert_eq!(max_coverage_id(&[RangeInclusive::new(5, 3)]), None);
+        assert_eq!(
+            max_coverage_id(&[RangeInclusive::new(5, 3), 1..=2, 2..=4]),
+            Some((2, 2))
+        );
+    }
+
     #[test]
     fn test_solve_day05() {
         let input = r"
@@ -1,17 +1,22 @@
-use std::{fs, ops::Range, str::FromStr};
+use std::{ops::Range, str::FromStr};
 
-use advent_of_code_2025::Part;
+use advent_of_code_2025::{Part, input::read_input, parse::split_blocks};
 use anyhow::Result;
 use grid::Grid;
 use itertools::Itertools;
-use strum::EnumString;
+use strum::{Display, EnumString};
 
-#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+/// Parses from and displays as the operator symbol (e.g. `*`).
+#[derive(Debug, Clone, Copy, PartialEq, EnumString, Display)]
 enum Operator {
     #[strum(serialize = "+")]
     Add,
     #[strum(serialize = "*")]
     Multiply,
+    #[strum(serialize = "-")]
+    Subtract,
+    #[strum(serialize = "/")]
+    Divide,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,7 +33,7 @@ impl Operation {
     }
 }
 
-/// Read each column's number vertically to form full equation.
+/// Direction of reading the numbers of each operation from the input.
 ///
 /// # Example
 ///
@@ -38,50 +43,42 @@ impl Operation {
 ///   6 98  
 /// *   +   
 /// ```
-///
-/// Above becomes `123 * 45 * 6` and `328 + 64 + 98`.
-fn parse_input_for_part_1(input: &str) -> Vec<Operation> {
-    let words: Vec<&str> = input
-        .lines()
-        .flat_map(|line| line.split_whitespace().collect::<Vec<&str>>())
-        .collect();
-
-    let width = words
-        .len()
-        .checked_div(input.lines().count())
-        .unwrap_or_default();
-
-    let mut grid = Grid::from_vec(words, width);
-    grid.rotate_right(); // so that each row is an operation (e.g. ["*", "6", "45", "123"])
-
-    grid.iter_rows()
-        .map(|mut row_iter| {
-            let operator = Operator::from_str(row_iter.next().expect("row should not be empty"))
-                .expect("unrecognized operator");
-
-            let operands: Vec<u64> = row_iter
-                .map(|number_str| number_str.parse().expect("expected valid number"))
-                .rev() // as `grid.rotate_right()` reversed the order of operands
-                .collect();
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReadOrder {
+    /// Each row of a number column is a number, read from top to bottom (Part One). Operations are
+    /// read from left to right.
+    ///
+    /// e.g. Above becomes `123 * 45 * 6` and `328 + 64 + 98`.
+    Columns,
+    /// Each digit column is a number, with digits read from top to bottom (Part Two). Digit columns
+    /// and operations are read from right to left.
+    ///
+    /// e.g. Above becomes `8 + 248 + 369` and `356 * 24 * 1`.
+    ColumnsReversed,
+}
 
-            Operation::new(operands, operator)
-        })
-        .collect()
+impl From<Part> for ReadOrder {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => ReadOrder::Columns,
+            Part::Two => ReadOrder::ColumnsReversed,
+        }
+    }
 }
 
-/// Start from every vertical column from right to left, and read digits from top to down
-///
-/// # Example
-///
-/// ```txt
-/// 123 328
-///  45 64  
-///   6 98  
-/// *   +   
-/// ```
-/// Above becomes `8 + 248 + 369` and `356 * 24 * 1`
-fn parse_input_for_part_2(input: &str) -> Vec<Operation> {
+/// Parses the input into a list of operations, reading the numbers in the given `order`.
+fn parse_input(input: &str, order: ReadOrder) -> Vec<Operation> {
     let mut lines_iter = input.lines();
+    let Some(operators_line) = lines_iter.next_back() else {
+        return vec![];
+    };
+    let number_lines: Vec<&str> = lines_iter.collect();
+    let width = number_lines
+        .iter()
+        .map(|line| line.len())
+        .chain([operators_line.len()])
+        .max()
+        .unwrap_or_default();
 
     // Pattern: The operator symbol is always the leftmost position of a "number column", we can use
     // spacing between operators to deduce the index range of each "number column"
@@ -92,7 +89,6 @@ fn parse_input_for_part_2(input: &str) -> Vec<Operation> {
     //  45  76    -->   operators_with_col_range = [(Operator::Multiply, 0..3), (Operator::Add, 4..7)]
     //   6 543
     // *   +
-    let operators_line = lines_iter.next_back().expect("operators row is missing");
     let operators_with_col_range: Vec<(Operator, Range<usize>)> = operators_line
         .chars()
         .enumerate()
@@ -102,32 +98,60 @@ fn parse_input_for_part_2(input: &str) -> Vec<Operation> {
                     // The `- 1` in `idx - 1` is to ignore a single whitespace between 2 adjacent number columns
                     acc.push((old_op, old_range.start..idx - 1));
                 }
-                acc.push((operator, idx..operators_line.len()));
+                acc.push((operator, idx..width));
             }
             acc
         });
 
-    let number_lines: Vec<&str> = lines_iter.collect();
-    operators_with_col_range
+    let mut operations: Vec<Operation> = operators_with_col_range
         .iter()
         .map(|(operator, col_range)| {
-            let operands: Vec<u64> = col_range
-                .clone()
-                .rev() // since we read columns right-to-left
-                .map(|col_idx| {
-                    // Read every column from top to bottom to get each operand
-                    number_lines
-                        .iter()
-                        .map(|line| line.get(col_idx..col_idx + 1).unwrap())
-                        .join("")
-                        .trim()
-                        .parse::<u64>()
-                        .expect("expected valid number")
+            // Part of each line in the number column (e.g. ["123", " 45", "  6"]). Trailing
+            // whitespaces may be trimmed from the input, so a line can end before the column does.
+            let rows: Vec<&str> = number_lines
+                .iter()
+                .map(|line| {
+                    let end = col_range.end.min(line.len());
+                    line.get(col_range.start.min(end)..end).unwrap_or_default()
                 })
                 .collect();
+
+            let operands: Vec<u64> = match order {
+                ReadOrder::Columns => rows
+                    .iter()
+                    .map(|row| row.trim().parse().expect("expected valid number"))
+                    .collect(),
+                ReadOrder::ColumnsReversed => (0..col_range.len())
+                    .rev()
+                    .map(|digit_idx| {
+                        // Read every digit column from top to bottom to get each operand
+                        rows.iter()
+                            .map(|row| row.get(digit_idx..digit_idx + 1).unwrap_or_default())
+                            .join("")
+                            .trim()
+                            .parse()
+                            .expect("expected valid number")
+                    })
+                    .collect(),
+            };
             Operation::new(operands, *operator)
         })
-        .rev() // read entire "number columns" right-to-left
+        .collect();
+
+    if order == ReadOrder::ColumnsReversed {
+        operations.reverse(); // read entire "number columns" right-to-left
+    }
+    operations
+}
+
+/// Parses an input with multiple equation blocks separated by blank lines. Each block is parsed
+/// separately with the parser of the given `part`.
+#[allow(dead_code)]
+fn parse_multi_block(input: &str, part: Part) -> Vec<Vec<Operation>> {
+    let order = ReadOrder::from(part);
+    split_blocks(input)
+        .into_iter()
+        .map(|block| parse_input(block, order))
         .collect()
 }
 
@@ -214,35 +238,86 @@ fn parse_input_for_part_2_alternative(input: &str) -> Vec<Operation> {
         .collect()
 }
 
-fn compute_operation(operation: &Operation) -> u64 {
-    let init = match operation.operator {
-        Operator::Add => 0,
-        Operator::Multiply => 1,
+/// Applies the operator to the operands from left to right (e.g. `12 - 3 - 4` = `(12 - 3) - 4`).
+///
+/// The result is signed since subtraction can go below zero (e.g. `5 - 10` = `-5`). Division is
+/// integer division rounding toward zero (e.g. `7 / 2` = `3`).
+///
+/// Returns an error when dividing by zero, or when an operand or result doesn't fit in an `i64`.
+fn compute_operation(operation: &Operation) -> Result<i64> {
+    let Some((&first, rest)) = operation.operands.split_first() else {
+        return Ok(empty_operation_value(operation.operator));
     };
-    operation
-        .operands
-        .iter()
-        .fold(init, |acc, operand| match operation.operator {
-            Operator::Add => acc + operand,
-            Operator::Multiply => acc * operand,
+    rest.iter()
+        .try_fold(i64::try_from(first)?, |acc, &operand| {
+            apply_operator(operation.operator, acc, operand)
         })
 }
 
-fn solve_day06(input: &str, part: Part) -> u64 {
-    let parser = match part {
-        Part::One => parse_input_for_part_1,
-        Part::Two => parse_input_for_part_2,
-    };
-    let operations = parser(input);
-    operations.iter().map(compute_operation).sum()
+/// Same as [compute_operation], but returns the running total after folding in each operand, so
+/// it's easy to spot where an unexpected value appears in a long chain.
+///
+/// The trace starts with the value of an operation without operands (`1` for `*` and `/`, `0` for
+/// `+` and `-`), and the first operand starts the running total. The last value is the result of
+/// [compute_operation].
+///
+/// e.g. `123 * 45 * 6` gives `[1, 123, 5535, 33210]`, and `12 - 3 - 4` gives `[0, 12, 9, 5]`.
+#[allow(dead_code)]
+fn compute_operation_trace(operation: &Operation) -> Result<Vec<i64>> {
+    let mut trace = vec![empty_operation_value(operation.operator)];
+    for (idx, &operand) in operation.operands.iter().enumerate() {
+        let total = match idx {
+            0 => i64::try_from(operand)?,
+            _ => apply_operator(operation.operator, trace[idx], operand)?,
+        };
+        trace.push(total);
+    }
+    Ok(trace)
+}
+
+/// Result of an operation without any operands.
+fn empty_operation_value(operator: Operator) -> i64 {
+    match operator {
+        Operator::Multiply | Operator::Divide => 1,
+        Operator::Add | Operator::Subtract => 0,
+    }
+}
+
+/// Applies the operator to the running total `acc` and the next operand.
+///
+/// Returns an error when dividing by zero, or when the operand or result doesn't fit in an `i64`.
+fn apply_operator(operator: Operator, acc: i64, operand: u64) -> Result<i64> {
+    let value = i64::try_from(operand)?;
+    match operator {
+        Operator::Add => acc.checked_add(value),
+        Operator::Multiply => acc.checked_mul(value),
+        Operator::Subtract => acc.checked_sub(value),
+        Operator::Divide => {
+            if value == 0 {
+                anyhow::bail!("Division by zero in {acc} / {operand}");
+            }
+            acc.checked_div(value)
+        }
+    }
+    .ok_or_else(|| anyhow::anyhow!("Overflow in {acc} {operator} {operand}"))
+}
+
+fn solve_day06(input: &str, part: Part) -> Result<i64> {
+    let operations = parse_input(input, ReadOrder::from(part));
+    operations.iter().try_fold(0_i64, |total, operation| {
+        let value = compute_operation(operation)?;
+        total
+            .checked_add(value)
+            .ok_or_else(|| anyhow::anyhow!("Overflow in {total} + {value}"))
+    })
 }
 
 fn main() -> Result<()> {
     // NOTE: Do NOT `trim_end()` because the whitespaces after the last line matters
-    let input = fs::read_to_string("puzzle_inputs/day06.txt")?;
+    let input = read_input("puzzle_inputs/day06.txt")?;
 
-    let part_1_solution = solve_day06(&input, Part::One);
-    let part_2_solution = solve_day06(&input, Part::Two);
+    let part_1_solution = solve_day06(&input, Part::One)?;
+    let part_2_solution = solve_day06(&input, Part::Two)?;
     println!("Part 1 Solution: {part_1_solution}");
     println!("Part 2 Solution: {part_2_solution}");
     Ok(())
@@ -254,7 +329,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     #[test]
-    fn test_parse_input_for_part_1() {
+    fn test_parse_input_columns() {
         // Puzzle Example
         let input = r"
 123 328  51 64 
@@ -265,7 +340,7 @@ mod tests {
         .trim();
 
         assert_eq!(
-            parse_input_for_part_1(input),
+            parse_input(input, ReadOrder::Columns),
             vec![
                 Operation::new(vec![123, 45, 6], Operator::Multiply),
                 Operation::new(vec![328, 64, 98], Operator::Add),
@@ -276,16 +351,16 @@ mod tests {
 
         let input = "1\n2\n3\n*";
         assert_eq!(
-            parse_input_for_part_1(input),
+            parse_input(input, ReadOrder::Columns),
             vec![Operation::new(vec![1, 2, 3], Operator::Multiply)]
         );
 
         let input = "";
-        assert_eq!(parse_input_for_part_1(input), vec![])
+        assert_eq!(parse_input(input, ReadOrder::Columns), vec![])
     }
 
     #[test]
-    fn test_parse_input_for_part_2() {
+    fn test_parse_input_columns_reversed() {
         // Puzzle example
         let input = r"
 123 328  51 64 
@@ -294,7 +369,7 @@ mod tests {
 *   +   *   +  "
             .trim_start();
         assert_eq!(
-            parse_input_for_part_2(input),
+            parse_input(input, ReadOrder::ColumnsReversed),
             vec![
                 Operation::new(vec![4, 431, 623], Operator::Add),
                 Operation::new(vec![175, 581, 32], Operator::Multiply),
@@ -311,7 +386,7 @@ mod tests {
 *      +      *      +     "
             .trim_start();
         assert_eq!(
-            parse_input_for_part_2(input),
+            parse_input(input, ReadOrder::ColumnsReversed),
             vec![
                 Operation::new(vec![136, 25, 14, 3, 2, 1], Operator::Add),
                 Operation::new(vec![6, 5, 4, 33, 22, 111], Operator::Multiply),
@@ -321,13 +396,188 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_input() {
+        // Puzzle example
+        let input = r"
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  "
+            .trim_start();
+
+        let operations = parse_input(input, ReadOrder::Columns);
+        assert_eq!(
+            operations,
+            vec![
+                Operation::new(vec![123, 45, 6], Operator::Multiply),
+                Operation::new(vec![328, 64, 98], Operator::Add),
+                Operation::new(vec![51, 387, 215], Operator::Multiply),
+                Operation::new(vec![64, 23, 314], Operator::Add),
+            ]
+        );
+
+        let operations = parse_input(input, ReadOrder::ColumnsReversed);
+        assert_eq!(
+            operations,
+            vec![
+                Operation::new(vec![4, 431, 623], Operator::Add),
+                Operation::new(vec![175, 581, 32], Operator::Multiply),
+                Operation::new(vec![8, 248, 369], Operator::Add),
+                Operation::new(vec![356, 24, 1], Operator::Multiply),
+            ]
+        );
+
+        assert_eq!(ReadOrder::from(Part::One), ReadOrder::Columns);
+        assert_eq!(ReadOrder::from(Part::Two), ReadOrder::ColumnsReversed);
+    }
+
+    #[test]
+    fn test_parse_multi_block() {
+        let input = r"
+123 328
+ 45 64 
+  6 98 
+*   +  
+
+5 1
+9 2
+- +"
+        .trim_start();
+
+        assert_eq!(
+            parse_multi_block(input, Part::One),
+            vec![
+                vec![
+                    Operation::new(vec![123, 45, 6], Operator::Multiply),
+                    Operation::new(vec![328, 64, 98], Operator::Add),
+                ],
+                vec![
+                    Operation::new(vec![5, 9], Operator::Subtract),
+                    Operation::new(vec![1, 2], Operator::Add),
+                ],
+            ]
+        );
+        assert_eq!(
+            parse_multi_block(input, Part::Two),
+            vec![
+                vec![
+                    Operation::new(vec![8, 248, 369], Operator::Add),
+                    Operation::new(vec![356, 24, 1], Operator::Multiply),
+                ],
+                vec![
+                    Operation::new(vec![12], Operator::Add),
+                    Operation::new(vec![59], Operator::Subtract),
+                ],
+            ]
+        );
+
+        // Single block is the same as parsing the whole input
+        let input = "5 1\n9 2\n- +";
+        assert_eq!(
+            parse_multi_block(input, Part::One),
+            vec![parse_input(input, ReadOrder::Columns)]
+        );
+        assert_eq!(
+            parse_multi_block("", Part::One),
+            Vec::<Vec<Operation>>::new()
+        );
+    }
+
+    #[test]
+    fn test_operator_display() {
+        assert_eq!(format!("{}", Operator::Add), "+");
+        assert_eq!(format!("{}", Operator::Multiply), "*");
+        assert_eq!(format!("{}", Operator::Subtract), "-");
+        assert_eq!(format!("{}", Operator::Divide), "/");
+
+        for operator in [
+            Operator::Add,
+            Operator::Multiply,
+            Operator::Subtract,
+            Operator::Divide,
+        ] {
+            assert_eq!(Operator::from_str(&operator.to_string()), Ok(operator));
+        }
+    }
+
     #[test]
     fn test_compute_operation() {
         let operation = Operation::new(vec![123, 45, 6], Operator::Multiply);
-        assert_eq!(compute_operation(&operation), 123 * 45 * 6);
+        assert_eq!(compute_operation(&operation).unwrap(), 123 * 45 * 6);
 
         let operation = Operation::new(vec![328, 64, 98], Operator::Add);
-        assert_eq!(compute_operation(&operation), 328 + 64 + 98);
+        assert_eq!(compute_operation(&operation).unwrap(), 328 + 64 + 98);
+
+        let operation = Operation::new(vec![12, 3, 4], Operator::Subtract);
+        assert_eq!(compute_operation(&operation).unwrap(), 5);
+
+        let operation = Operation::new(vec![5, 10], Operator::Subtract);
+        assert_eq!(compute_operation(&operation).unwrap(), -5);
+
+        let operation = Operation::new(vec![100, 5, 2], Operator::Divide);
+        assert_eq!(compute_operation(&operation).unwrap(), 10);
+
+        let operation = Operation::new(vec![7, 2], Operator::Divide);
+        assert_eq!(compute_operation(&operation).unwrap(), 3);
+
+        let operation = Operation::new(vec![100, 0, 2], Operator::Divide);
+        assert!(compute_operation(&operation).is_err());
+    }
+
+    #[test]
+    fn test_compute_operation_overflow() {
+        let operation = Operation::new(vec![u64::MAX], Operator::Add);
+        assert!(compute_operation(&operation).is_err());
+
+        let operation = Operation::new(vec![1, u64::MAX], Operator::Add);
+        assert!(compute_operation(&operation).is_err());
+
+        let operation = Operation::new(vec![i64::MAX as u64, 1], Operator::Add);
+        assert!(compute_operation(&operation).is_err());
+
+        let operation = Operation::new(vec![1 << 32, 1 << 32], Operator::Multiply);
+        assert!(compute_operation(&operation).is_err());
+
+        let operation = Operation::new(vec![0, i64::MAX as u64, 2], Operator::Subtract);
+        assert!(compute_operation(&operation).is_err());
+        assert!(compute_operation_trace(&operation).is_err());
+
+        // Largest values that still fit
+        let operation = Operation::new(vec![i64::MAX as u64 - 1, 1], Operator::Add);
+        assert_eq!(compute_operation(&operation).unwrap(), i64::MAX);
+        let operation = Operation::new(vec![0, i64::MAX as u64, 1], Operator::Subtract);
+        assert_eq!(compute_operation(&operation).unwrap(), i64::MIN);
+
+        let input = format!("{}\n{}\n+", i64::MAX, i64::MAX);
+        assert!(solve_day06(&input, Part::One).is_err());
+    }
+
+    #[test]
+    fn test_compute_operation_trace() {
+        let operation = Operation::new(vec![123, 45, 6], Operator::Multiply);
+        assert_eq!(
+            compute_operation_trace(&operation).unwrap(),
+            vec![1, 123, 5535, 33210]
+        );
+
+        let operation = Operation::new(vec![328, 64, 98], Operator::Add);
+        assert_eq!(
+            compute_operation_trace(&operation).unwrap(),
+            vec![0, 328, 392, 490]
+        );
+
+        let operation = Operation::new(vec![12, 3, 4], Operator::Subtract);
+        assert_eq!(
+            compute_operation_trace(&operation).unwrap(),
+            vec![0, 12, 9, 5]
+        );
+
+        let operation = Operation::new(vec![], Operator::Multiply);
+        assert_eq!(compute_operation_trace(&operation).unwrap(), vec![1]);
+
+        let operation = Operation::new(vec![100, 0, 2], Operator::Divide);
+        assert!(compute_operation_trace(&operation).is_err());
     }
 
     #[test]
@@ -339,7 +589,18 @@ mod tests {
 *   +   *   +  "
             .trim_start();
 
-        assert_eq!(solve_day06(input, Part::One), 4277556);
-        assert_eq!(solve_day06(input, Part::Two), 3263827);
+        assert_eq!(solve_day06(input, Part::One).unwrap(), 4277556);
+        assert_eq!(solve_day06(input, Part::Two).unwrap(), 3263827);
+
+        let input = "5 1\n9 2\n- +";
+        assert_eq!(solve_day06(input, Part::One).unwrap(), (5 - 9) + (1 + 2));
+
+        let input = "12 100\n 3   5\n 4   2\n-  /  ";
+        assert_eq!(solve_day06(input, Part::One).unwrap(), 5 + 10);
+        // Reads `52 / 0 / 1` for Part Two
+        assert!(solve_day06(input, Part::Two).is_err());
+
+        let input = "1 4\n0 0\n- /";
+        assert!(solve_day06(input, Part::One).is_err());
     }
 }
@@ -1,6 +1,6 @@
-use std::{fmt::Display, fs};
+use std::{cmp::Reverse, fmt::Display};
 
-use advent_of_code_2025::{Part, grid::parse_string_to_grid};
+use advent_of_code_2025::{Part, grid::parse_string_to_grid, input::read_input};
 use anyhow::{Error, Result};
 use grid::*;
 
@@ -12,6 +12,8 @@ enum Cell {
     Start,
     /// A beam splitter (`^`)
     Splitter,
+    /// A wall (`#`) that absorbs any beam hitting it
+    Wall,
     /// A beam (`|`). It holds a numeric "weight" that indicates how many path combinations can the
     /// the beam arrive here from the source.
     Beam(usize),
@@ -23,6 +25,7 @@ impl Display for Cell {
             Cell::Empty => write!(f, "."),
             Cell::Start => write!(f, "S"),
             Cell::Splitter => write!(f, "^"),
+            Cell::Wall => write!(f, "#"),
             Cell::Beam(_) => write!(f, "|"),
         }
     }
@@ -36,12 +39,68 @@ impl TryFrom<char> for Cell {
             '.' => Ok(Cell::Empty),
             'S' => Ok(Cell::Start),
             '^' => Ok(Cell::Splitter),
+            '#' => Ok(Cell::Wall),
             '|' => Ok(Cell::Beam(1)), // we don't know the actual weight of beam, so default to 1
             _ => Err(anyhow::anyhow!("Invalid cell character '{value}'")),
         }
     }
 }
 
+/// Pads every line of the input with empty space (`.`) so that all lines have equal width, since
+/// [parse_string_to_grid] errors on ragged lines. Trailing whitespaces of each line are removed first.
+///
+/// e.g. `"..S\n.\n.^.^ "` becomes `"..S.\n....\n.^.^"`
+fn normalize_beam_input(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().map(|line| line.trim_end()).collect();
+    let width = lines
+        .iter()
+        .map(|line| line.len())
+        .max()
+        .unwrap_or_default();
+    lines
+        .iter()
+        .map(|line| format!("{line:.<width$}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Checks that the grid is a valid puzzle input before shooting the beam, i.e. there's exactly one
+/// start position (`S`) which is in the first row, and there are no beams (`|`) yet.
+#[allow(dead_code)]
+fn validate_day07_input(grid: &Grid<Cell>) -> Result<()> {
+    let start_coords: Vec<(usize, usize)> = grid
+        .indexed_iter()
+        .filter(|(_, cell)| **cell == Cell::Start)
+        .map(|(coords, _)| coords)
+        .collect();
+    match start_coords[..] {
+        [] => return Err(anyhow::anyhow!("Start position 'S' is missing")),
+        [(0, _)] => {}
+        [(row, col)] => {
+            return Err(anyhow::anyhow!(
+                "Start position 'S' should be in the first row, but found at ({row}, {col})"
+            ));
+        }
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Expected exactly one start position 'S', but found {} at {start_coords:?}",
+                start_coords.len()
+            ));
+        }
+    }
+
+    if let Some(((row, col), _)) = grid
+        .indexed_iter()
+        .find(|(_, cell)| matches!(cell, Cell::Beam(_)))
+    {
+        return Err(anyhow::anyhow!(
+            "Input should not contain beams '|', but found one at ({row}, {col})"
+        ));
+    }
+
+    Ok(())
+}
+
 /// Move the beams forward by 1 row at row number `row_idx` (zero-based).
 ///
 /// # High-Level Example
@@ -69,6 +128,11 @@ impl TryFrom<char> for Cell {
 ///                                                         2   9   4   <- new beam weight
 ///                                                             ╰─ 2 + 3 + 4
 /// ```
+///
+/// # Walls
+///
+/// A wall (`#`) absorbs the beam, so a beam hitting a wall below contributes nothing. If a splitter
+/// is next to a wall, the weight only goes to the open side.
 fn next_tick(grid: &mut Grid<Cell>, row_idx: usize) -> (&Grid<Cell>, usize) {
     assert!(row_idx > 0, "row_idx should be greater than 0");
 
@@ -88,6 +152,7 @@ fn next_tick(grid: &mut Grid<Cell>, row_idx: usize) -> (&Grid<Cell>, usize) {
                 *grid.get_mut(row_idx, col_idx).unwrap() =
                     Cell::Beam(above_weight + current_weight);
             }
+            (Cell::Beam(_), Cell::Wall) => {}
             (Cell::Beam(above_weight), Cell::Splitter) => {
                 total_splits += 1;
 
@@ -98,7 +163,7 @@ fn next_tick(grid: &mut Grid<Cell>, row_idx: usize) -> (&Grid<Cell>, usize) {
                         let new_weight = match *adjacent_cell {
                             Cell::Beam(existing_weight) => Some(above_weight + existing_weight),
                             Cell::Empty => Some(above_weight),
-                            _ => None,
+                            Cell::Wall | Cell::Start | Cell::Splitter => None,
                         };
                         if let Some(w) = new_weight {
                             *adjacent_cell = Cell::Beam(w)
@@ -122,7 +187,8 @@ fn shoot_beam_and_count_splits(cell_grid: &mut Grid<Cell>) -> (&Grid<Cell>, usiz
     (cell_grid, total_splits)
 }
 
-/// Counts number of possible paths a beam can travel.
+/// Counts number of possible paths a beam can travel. Walls in the last row absorb beams, so they
+/// add nothing to the count.
 fn count_beam_possible_paths(cell_grid: &Grid<Cell>) -> usize {
     cell_grid
         .iter_rows()
@@ -135,8 +201,37 @@ fn count_beam_possible_paths(cell_grid: &Grid<Cell>) -> usize {
         .sum()
 }
 
+/// Finds the bottom-row column with the largest beam weight, i.e. where the most paths end up.
+/// The grid should be propagated already (see [shoot_beam_and_count_splits]).
+///
+/// Returns `(column, weight)`, or `None` if no beams reach the bottom row. If several columns share
+/// the largest weight, the leftmost one is returned.
+#[allow(dead_code)]
+fn max_weight_column(grid: &Grid<Cell>) -> Option<(usize, usize)> {
+    grid.iter_rows()
+        .next_back()?
+        .enumerate()
+        .filter_map(|(col, &cell)| match cell {
+            Cell::Beam(weight) => Some((col, weight)),
+            _ => None,
+        })
+        .max_by_key(|&(col, weight)| (weight, Reverse(col)))
+}
+
+/// Counts the cells that the beam occupies after it reaches the end, i.e. how much of the grid is lit
+/// up by the beam. The grid is propagated on a copy, so `grid` can be freshly parsed.
+#[allow(dead_code)]
+fn beam_footprint(grid: &Grid<Cell>) -> usize {
+    let mut grid = grid.clone();
+    shoot_beam_and_count_splits(&mut grid);
+    grid.iter()
+        .filter(|cell| matches!(cell, Cell::Beam(_)))
+        .count()
+}
+
 fn solve_day07(input: &str, part: Part) -> usize {
-    let mut cell_grid = parse_string_to_grid(input, Cell::try_from).expect("input should be valid");
+    let mut cell_grid = parse_string_to_grid(&normalize_beam_input(input), Cell::try_from)
+        .expect("input should be valid");
     let (_, total_splits) = shoot_beam_and_count_splits(&mut cell_grid);
     match part {
         Part::One => total_splits,
@@ -145,7 +240,7 @@ fn solve_day07(input: &str, part: Part) -> usize {
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day07.txt")?;
+    let input = read_input("puzzle_inputs/day07.txt")?;
 
     let part_1_solution = solve_day07(&input, Part::One);
     let part_2_solution = solve_day07(&input, Part::Two);
@@ -177,6 +272,43 @@ mod tests {
         assert_eq!(grid.unwrap(), expected_grid);
     }
 
+    #[test]
+    fn test_normalize_beam_input() {
+        let input = "..S\n.\n.^.^  \n";
+        assert_eq!(normalize_beam_input(input), "..S.\n....\n.^.^");
+        assert!(parse_string_to_grid(input, Cell::try_from).is_err());
+        assert_eq!(
+            parse_string_to_grid(&normalize_beam_input(input), Cell::try_from).unwrap(),
+            parse_string_to_grid("..S.\n....\n.^.^", Cell::try_from).unwrap()
+        );
+
+        // Already rectangular
+        assert_eq!(normalize_beam_input("..S..\n.^.^."), "..S..\n.^.^.");
+        assert_eq!(normalize_beam_input(""), "");
+    }
+
+    #[test]
+    fn test_validate_day07_input() {
+        let grid = parse_string_to_grid("..S..\n.....\n.^.^.", Cell::try_from).unwrap();
+        assert!(validate_day07_input(&grid).is_ok());
+
+        // Missing `S`
+        let grid = parse_string_to_grid(".....\n.....\n.^.^.", Cell::try_from).unwrap();
+        assert!(validate_day07_input(&grid).is_err());
+
+        // Multiple `S`
+        let grid = parse_string_to_grid(".S.S.\n.....\n.^.^.", Cell::try_from).unwrap();
+        assert!(validate_day07_input(&grid).is_err());
+
+        // `S` not in first row
+        let grid = parse_string_to_grid(".....\n..S..\n.^.^.", Cell::try_from).unwrap();
+        assert!(validate_day07_input(&grid).is_err());
+
+        // Pre-existing beam
+        let grid = parse_string_to_grid("..S..\n..|..\n.^.^.", Cell::try_from).unwrap();
+        assert!(validate_day07_input(&grid).is_err());
+    }
+
     #[test]
     fn test_next_tick() {
         // ...
@@ -252,6 +384,109 @@ mod tests {
         assert_eq!(next_tick(&mut input, 1), (&expected_output, 2_usize));
     }
 
+    #[test]
+    fn test_next_tick_with_walls() {
+        // .|.
+        // .#.
+        let mut input = grid![
+            [Cell::Empty, Cell::Beam(3), Cell::Empty]
+            [Cell::Empty, Cell::Wall, Cell::Empty]
+        ];
+        let expected_output = input.clone();
+        assert_eq!(next_tick(&mut input, 1), (&expected_output, 0_usize));
+
+        // .|.
+        // #^.
+        let mut input = grid![
+            [Cell::Empty, Cell::Beam(3), Cell::Empty]
+            [Cell::Wall, Cell::Splitter, Cell::Empty]
+        ];
+        let expected_output = grid![
+            [Cell::Empty, Cell::Beam(3), Cell::Empty]
+            [Cell::Wall, Cell::Splitter, Cell::Beam(3)]
+        ];
+        assert_eq!(next_tick(&mut input, 1), (&expected_output, 1_usize));
+    }
+
+    #[test]
+    fn test_count_beam_possible_paths_with_walls() {
+        let mut grid = parse_string_to_grid(".S.\n...\n.^#", Cell::try_from).unwrap();
+        shoot_beam_and_count_splits(&mut grid);
+        assert_eq!(grid[(2, 0)], Cell::Beam(1));
+        assert_eq!(grid[(2, 2)], Cell::Wall);
+        assert_eq!(count_beam_possible_paths(&grid), 1);
+
+        let mut grid = parse_string_to_grid(".S.\n...\n.#.", Cell::try_from).unwrap();
+        shoot_beam_and_count_splits(&mut grid);
+        assert_eq!(count_beam_possible_paths(&grid), 0);
+    }
+
+    #[test]
+    fn test_beam_footprint() {
+        let input = r"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+..............."
+            .trim();
+        let grid = parse_string_to_grid(input, Cell::try_from).unwrap();
+        // Rows have 1, 2, 2, 3, 3, 4, 4, 6, 6, 7, 7, 9, 9, 9, 9 beams
+        assert_eq!(beam_footprint(&grid), 81);
+
+        // Both beams hit splitters at the edges, and their inner halves overlap in the middle
+        let grid = parse_string_to_grid(".S.\n...\n.^.\n^.^\n...", Cell::try_from).unwrap();
+        assert_eq!(beam_footprint(&grid), 1 + 2 + 1 + 1);
+
+        let grid = parse_string_to_grid("S", Cell::try_from).unwrap();
+        assert_eq!(beam_footprint(&grid), 0);
+    }
+
+    #[test]
+    fn test_max_weight_column() {
+        let input = r"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+..............."
+            .trim();
+        let mut grid = parse_string_to_grid(input, Cell::try_from).unwrap();
+        shoot_beam_and_count_splits(&mut grid);
+        // Bottom row weights are `1 0 2 0 10 0 11 0 11 0 2 1 1 0 1`
+        assert_eq!(max_weight_column(&grid), Some((6, 11)));
+
+        // Beam is blocked before reaching the bottom
+        let mut grid = parse_string_to_grid(".S.\n...\n^^^\n...", Cell::try_from).unwrap();
+        shoot_beam_and_count_splits(&mut grid);
+        assert_eq!(max_weight_column(&grid), None);
+
+        let grid = parse_string_to_grid("...", Cell::try_from).unwrap();
+        assert_eq!(max_weight_column(&grid), None);
+    }
+
     #[test]
     fn test_solve_day07() {
         // Puzzle example
@@ -1,20 +1,15 @@
 use std::collections::{HashMap, HashSet};
-use std::fs;
 
-use advent_of_code_2025::{Part, coords::Coords3D, line::Line3D};
+use advent_of_code_2025::{
+    Part, coords::Coords3D, input::read_input, line::Line3D, union_find::UnionFind,
+};
 use anyhow::Result;
 use itertools::{Itertools, iproduct};
 
-fn parse_input_to_coords(input: &str) -> Vec<Coords3D> {
+fn parse_input_to_coords(input: &str) -> Result<Vec<Coords3D>> {
     input
         .lines()
-        .map(|line| {
-            let values: Vec<i64> = line
-                .split(",")
-                .map(|num_str| num_str.parse().expect("should be a valid integer"))
-                .collect();
-            Coords3D::new(values[0], values[1], values[2])
-        })
+        .map(|line| Ok(line.parse::<Coords3D>()?))
         .collect()
 }
 
@@ -33,11 +28,48 @@ fn build_edge_length_map(coords: &[Coords3D]) -> HashMap<Line3D, f64> {
     line_to_length_map
 }
 
-/// Connects 2 junction boxes together in the list of circuits.
-///
-/// Each circuit is a `HashSet<Coords3D>` containing list of junction box coordinates interconnected.
-/// Connecting two boxes from different circuits will join the two circuits together. If two boxes
-/// are from same circuit, nothing happens.
+/// Sorts all lines from [build_edge_length_map] in the order they're connected, i.e. ascending order
+/// of their length. Lines with equal length are sorted in ascending order of [Line3D::canonical], so
+/// the order is deterministic.
+fn sorted_edges(coords: &[Coords3D]) -> Vec<(Line3D, f64)> {
+    build_edge_length_map(coords)
+        .into_iter()
+        .sorted_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap()
+                .then_with(|| a.0.canonical().cmp(&b.0.canonical()))
+        })
+        .collect()
+}
+
+/// Same as [build_edge_length_map], but stores the squared length of each line as an integer. The
+/// squared lengths are in the same order as the lengths, so they can be sorted without floating
+/// point numbers.
+fn build_edge_squared_length_map(coords: &[Coords3D]) -> HashMap<Line3D, i64> {
+    iproduct!(coords, coords)
+        .filter(|(coord_a, coord_b)| coord_a != coord_b)
+        .map(|(coord_a, coord_b)| {
+            let line = Line3D(*coord_a, *coord_b);
+            let squared_length = line.len_squared();
+            (line, squared_length)
+        })
+        .collect()
+}
+
+/// Same as [sorted_edges], but sorts the lines from [build_edge_squared_length_map], so no floating
+/// point comparison is involved.
+fn sorted_edges_by_squared_length(coords: &[Coords3D]) -> Vec<(Line3D, i64)> {
+    build_edge_squared_length_map(coords)
+        .into_iter()
+        .sorted_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then_with(|| a.0.canonical().cmp(&b.0.canonical()))
+        })
+        .collect()
+}
+
+/// Junction boxes connected together, where each circuit is a set in [UnionFind] and each box is
+/// identified by its index in the input coordinates.
 ///
 /// # Example
 ///
@@ -46,36 +78,57 @@ fn build_edge_length_map(coords: &[Coords3D]) -> HashMap<Line3D, f64> {
 /// [D, E]      --- connect A and D -->   [F]               --- connect A and E --->  [F]
 /// [F]             (diff circuits)                             (same circuit)
 /// ```
-fn connect_junction_box<'a>(
-    circuits: &'a mut Vec<HashSet<&Coords3D>>,
-    box_a: &'a Coords3D,
-    box_b: &'a Coords3D,
-) {
-    let box_a_circuit_idx = circuits
-        .iter()
-        .position(|circuit| circuit.contains(box_a))
-        .expect("circuits should contain coord_a");
-    let box_b_circuit_idx = circuits
-        .iter()
-        .position(|circuit| circuit.contains(box_b))
-        .expect("circuits should contain coord_b");
+struct Circuits<'a> {
+    coords: &'a [Coords3D],
+    index_of: HashMap<&'a Coords3D, usize>,
+    sets: UnionFind,
+}
+
+impl<'a> Circuits<'a> {
+    /// Starts with every junction box in its own circuit.
+    fn new(coords: &'a [Coords3D]) -> Self {
+        Self {
+            coords,
+            index_of: coords.iter().enumerate().map(|(i, c)| (c, i)).collect(),
+            sets: UnionFind::new(coords.len()),
+        }
+    }
 
-    if box_a_circuit_idx == box_b_circuit_idx {
-        return; // No-op if two boxes already in same circuit
+    /// Connects the 2 junction boxes of the line. Returns whether two circuits are joined together.
+    fn connect(&mut self, line: &Line3D) -> bool {
+        self.sets
+            .union(self.index_of[&line.0], self.index_of[&line.1])
     }
 
-    // Remove the circuit with higher index to avoid shifting
-    let (remove_idx, keep_idx) = if box_b_circuit_idx > box_a_circuit_idx {
-        (box_b_circuit_idx, box_a_circuit_idx)
-    } else {
-        (box_a_circuit_idx, box_b_circuit_idx)
-    };
-    let removed_circuit = circuits.remove(remove_idx);
-    circuits[keep_idx].extend(removed_circuit);
+    fn count(&self) -> usize {
+        self.sets.count_sets()
+    }
+
+    /// Lists the circuits, where each circuit is a `HashSet<Coords3D>` containing list of junction
+    /// box coordinates interconnected. Circuits are in ascending order of their first box's position
+    /// in the input coordinates.
+    fn sets(&mut self) -> Vec<HashSet<&'a Coords3D>> {
+        let mut circuit_idx_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut circuits: Vec<HashSet<&Coords3D>> = vec![];
+        for (idx, coord) in self.coords.iter().enumerate() {
+            let root = self.sets.find(idx);
+            let circuit_idx = *circuit_idx_of_root.entry(root).or_insert_with(|| {
+                circuits.push(HashSet::new());
+                circuits.len() - 1
+            });
+            circuits[circuit_idx].insert(coord);
+        }
+        circuits
+    }
 }
 
-/// Connects 2 [Coords3D] in ascending order of their distance for `rounds` times. The iteration
-/// always ends if the connection causes all junction boxes to form a single circuit.
+/// Connects 2 [Coords3D] in ascending order of their distance for `rounds` times (see [sorted_edges]).
+/// The iteration always ends if the connection causes all junction boxes to form a single circuit.
+///
+/// Each round uses up one of the shortest lines, even if both boxes are already in the same circuit
+/// and nothing happens. This is the puzzle's interpretation of "connect the 1000 pairs of junction
+/// boxes which are closest together" in Part One. See [connect_junction_boxes_by_merges] for
+/// counting only the connections that join two circuits.
 ///
 /// # Returns
 ///
@@ -87,33 +140,153 @@ fn connect_junction_boxes(
     coords: &[Coords3D],
     rounds: usize,
 ) -> (Vec<HashSet<&Coords3D>>, Option<Line3D>) {
-    let edge_length_map = build_edge_length_map(coords);
+    let edges = sorted_edges(coords).into_iter().map(|(line, _)| line);
+    connect_junction_boxes_until(coords, edges, |edges_count, _| edges_count == rounds)
+}
 
-    let mut circuits: Vec<HashSet<&Coords3D>> =
-        coords.iter().map(|coord| HashSet::from([coord])).collect();
+/// Same as [connect_junction_boxes], but the lines are sorted by their squared length (see
+/// [sorted_edges_by_squared_length]) to avoid floating point comparisons.
+#[allow(dead_code)]
+fn connect_junction_boxes_by_squared_length(
+    coords: &[Coords3D],
+    rounds: usize,
+) -> (Vec<HashSet<&Coords3D>>, Option<Line3D>) {
+    let edges = sorted_edges_by_squared_length(coords)
+        .into_iter()
+        .map(|(line, _)| line);
+    connect_junction_boxes_until(coords, edges, |edges_count, _| edges_count == rounds)
+}
 
-    let shortest_edges = edge_length_map
-        .iter()
-        .sorted_by(|a, b| a.1.partial_cmp(b.1).unwrap()) // sort in ascending line lengths
-        .take(rounds);
+/// Same as [connect_junction_boxes], but each round is a connection that joins two different
+/// circuits. Lines between boxes already in the same circuit are skipped without using up a round,
+/// so exactly `merges` connections are made unless all boxes form a single circuit first.
+#[allow(dead_code)]
+fn connect_junction_boxes_by_merges(
+    coords: &[Coords3D],
+    merges: usize,
+) -> (Vec<HashSet<&Coords3D>>, Option<Line3D>) {
+    let edges = sorted_edges(coords).into_iter().map(|(line, _)| line);
+    connect_junction_boxes_until(coords, edges, |_, merges_count| merges_count == merges)
+}
+
+/// Connects 2 [Coords3D] along the `edges` in order until `should_stop(edges_count, merges_count)`
+/// is true, or all junction boxes form a single circuit. `edges_count` is the number of lines used
+/// so far, and `merges_count` is the number of those that joined two circuits.
+fn connect_junction_boxes_until<F: Fn(usize, usize) -> bool>(
+    coords: &[Coords3D],
+    edges: impl IntoIterator<Item = Line3D>,
+    should_stop: F,
+) -> (Vec<HashSet<&Coords3D>>, Option<Line3D>) {
+    let mut circuits = Circuits::new(coords);
 
     let mut final_line: Option<Line3D> = None;
+    let mut merges_count = 0;
 
-    for (line, _) in shortest_edges {
-        connect_junction_box(&mut circuits, &line.0, &line.1);
+    for (edges_count, line) in edges.into_iter().enumerate() {
+        if should_stop(edges_count, merges_count) {
+            break;
+        }
+        if circuits.connect(&line) {
+            merges_count += 1;
+        }
 
-        if final_line.is_none() && circuits.len() == 1 {
-            final_line = Some(line.clone());
+        if final_line.is_none() && circuits.count() == 1 {
+            final_line = Some(line);
             break;
         }
     }
 
-    (circuits, final_line)
+    (circuits.sets(), final_line)
+}
+
+/// Connects junction boxes in ascending order of their distance until all of them form a single
+/// circuit, and records every connection that joins two different circuits. Each event is paired
+/// with the number of circuits left after the merge, so the last event leaves `1` circuit and is the
+/// final line connection in Part Two.
+#[allow(dead_code)]
+fn merge_events(coords: &[Coords3D]) -> Vec<(Line3D, usize)> {
+    let mut circuits = Circuits::new(coords);
+
+    let mut events = vec![];
+    for (line, _) in sorted_edges(coords) {
+        if circuits.count() <= 1 {
+            break;
+        }
+        if circuits.connect(&line) {
+            events.push((line, circuits.count()));
+        }
+    }
+    events
+}
+
+/// Returns the junction boxes that are not connected to any other box after connecting for `rounds`
+/// times (see [connect_junction_boxes]), in the same order as `coords`.
+#[allow(dead_code)]
+fn isolated_boxes(coords: &[Coords3D], rounds: usize) -> Vec<Coords3D> {
+    let (circuits, _) = connect_junction_boxes(coords, rounds);
+    let isolated: HashSet<&Coords3D> = circuits
+        .iter()
+        .filter(|circuit| circuit.len() == 1)
+        .flatten()
+        .copied()
+        .collect();
+    coords
+        .iter()
+        .filter(|coord| isolated.contains(coord))
+        .copied()
+        .collect()
+}
+
+/// Connects junction boxes for `rounds` times like [connect_junction_boxes], and sums up the lengths
+/// of the lines that joined two different circuits. Lines between boxes already in the same circuit
+/// are not counted.
+#[allow(dead_code)]
+fn total_wire_length(coords: &[Coords3D], rounds: usize) -> f64 {
+    let mut circuits = Circuits::new(coords);
+
+    let edges = sorted_edges(coords);
+    let mut total_length = 0.0;
+    for (line, length) in edges.iter().take(rounds) {
+        if circuits.connect(line) {
+            total_length += length;
+        }
+        if circuits.count() == 1 {
+            break;
+        }
+    }
+    total_length
+}
+
+/// Connects each junction box to its single nearest box, instead of taking the globally shortest
+/// lines like [connect_junction_boxes]. Distances are compared by [Line3D::len_squared], and ties
+/// are broken by [Line3D::canonical].
+///
+/// Returns the lines in the order of the boxes in `coords`. Two boxes which are each other's nearest
+/// box share one line, so it's only listed once at its first occurrence.
+#[allow(dead_code)]
+fn nearest_neighbor_graph(coords: &[Coords3D]) -> Vec<Line3D> {
+    let mut seen: HashSet<Line3D> = HashSet::new();
+    coords
+        .iter()
+        .filter_map(|coord| {
+            coords
+                .iter()
+                .filter(|other| *other != coord)
+                .map(|other| Line3D(*coord, *other))
+                .min_by_key(|line| (line.len_squared(), line.canonical()))
+        })
+        .filter(|line| seen.insert(line.clone()))
+        .collect()
 }
 
 /// Connects 2 coordinates in ascending order of their distance for `rounds` times, then get the
 /// 3 circuits with largest size, and multiply their sizes.
+///
+/// Returns `0` if there are no coordinates.
 fn solve_day08_part_1(coords: &[Coords3D], rounds: usize) -> usize {
+    if coords.is_empty() {
+        return 0;
+    }
     let (circuits, _) = connect_junction_boxes(coords, rounds);
     circuits
         .iter()
@@ -124,25 +297,28 @@ fn solve_day08_part_1(coords: &[Coords3D], rounds: usize) -> usize {
         .product()
 }
 
+/// Multiplies the x coordinates of the final line connection that makes all junction boxes form a
+/// single circuit.
+///
+/// Returns `0` if there are fewer than 2 coordinates, since no connection can be made.
 fn solve_day08_part_2(coords: &[Coords3D]) -> usize {
     let (_, final_line) = connect_junction_boxes(coords, usize::MAX);
-    let final_line = final_line.expect("final line connection should be present");
-    (final_line.0.x * final_line.1.x) as usize
+    final_line.map_or(0, |line| (line.0.x * line.1.x) as usize)
 }
 
-fn solve_day08(input: &str, part: Part) -> usize {
-    let coords = parse_input_to_coords(input);
-    match part {
+fn solve_day08(input: &str, part: Part) -> Result<usize> {
+    let coords = parse_input_to_coords(input)?;
+    Ok(match part {
         Part::One => solve_day08_part_1(&coords, 1000),
         Part::Two => solve_day08_part_2(&coords),
-    }
+    })
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day08.txt")?;
+    let input = read_input("puzzle_inputs/day08.txt")?;
 
-    let part_1_solution = solve_day08(&input, Part::One);
-    let part_2_solution = solve_day08(&input, Part::Two);
+    let part_1_solution = solve_day08(&input, Part::One)?;
+    let part_2_solution = solve_day08(&input, Part::Two)?;
     println!("Part 1 Solution: {part_1_solution}");
     println!("Part 2 Solution: {part_2_solution}");
     Ok(())
@@ -155,13 +331,64 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    /// Puzzle example.
+    const EXAMPLE_INPUT: &str = r"
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    fn example_coords() -> Vec<Coords3D> {
+        parse_input_to_coords(EXAMPLE_INPUT.trim()).unwrap()
+    }
+
+    /// Boxes `A` to `F` on the `z = 0` plane, small enough to work out the connections by hand.
+    fn small_coords() -> [Coords3D; 6] {
+        [
+            Coords3D::new(2, 2, 0),
+            Coords3D::new(4, 2, 0),
+            Coords3D::new(2, 5, 0),
+            Coords3D::new(6, 6, 0),
+            Coords3D::new(9, 5, 0),
+            Coords3D::new(10, 0, 0),
+        ]
+    }
+
     #[test]
     fn test_parse_input_to_coords() {
         let input = "162,817,812\n57,618,57";
         assert_eq!(
-            parse_input_to_coords(input),
+            parse_input_to_coords(input).unwrap(),
             vec![Coords3D::new(162, 817, 812), Coords3D::new(57, 618, 57)]
-        )
+        );
+
+        assert_eq!(parse_input_to_coords("").unwrap(), vec![]);
+        assert!(parse_input_to_coords("162,817").is_err());
+        assert!(parse_input_to_coords("162,817,abc").is_err());
+        assert!(parse_input_to_coords("162,817,812,1").is_err());
+        assert!(parse_input_to_coords("162,817,812,").is_err());
+        assert_eq!(
+            parse_input_to_coords("162,817,812\n57,618")
+                .unwrap_err()
+                .to_string(),
+            "expected 3 comma-separated values in '57,618', but found 2"
+        );
     }
 
     #[test]
@@ -182,50 +409,53 @@ mod tests {
     }
 
     #[test]
-    fn test_connect_junction_box() {
-        let [a, b, c, d, e, f] = [
-            &Coords3D::new(2, 2, 0),
-            &Coords3D::new(4, 2, 0),
-            &Coords3D::new(2, 5, 0),
-            &Coords3D::new(6, 6, 0),
-            &Coords3D::new(9, 5, 0),
-            &Coords3D::new(10, 0, 0),
-        ];
+    fn test_build_edge_squared_length_map() {
+        let coord_a = Coords3D::new(2, 2, 0);
+        let coord_b = Coords3D::new(2, 3, 0);
+        let coord_c = Coords3D::new(4, 2, 0);
+        let coords = vec![coord_a, coord_b, coord_c];
 
-        let mut circuits = vec![[a].into(), [b].into(), [c].into()];
-        connect_junction_box(&mut circuits, a, b);
-        assert_eq!(&circuits, &vec![[a, b].into(), [c].into()]);
+        let map = build_edge_squared_length_map(&coords);
 
-        let mut circuits = vec![[a].into(), [b].into(), [c].into()];
-        connect_junction_box(&mut circuits, c, a);
-        assert_eq!(&circuits, &vec![[a, c].into(), [b].into()]);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&Line3D(coord_a, coord_b)), Some(&1));
+        assert_eq!(map.get(&Line3D(coord_a, coord_c)), Some(&4));
+        assert_eq!(map.get(&Line3D(coord_b, coord_c)), Some(&5));
 
-        let mut circuits = vec![[a, b, c].into(), [d, e].into(), [f].into()];
-        connect_junction_box(&mut circuits, d, a);
-        assert_eq!(&circuits, &vec![[a, b, c, d, e].into(), [f].into()]);
+        assert!(!map.contains_key(&Line3D(coord_a, coord_a)));
+    }
 
-        let mut circuits = vec![[a, b].into(), [c, d].into(), [e, f].into()];
-        connect_junction_box(&mut circuits, d, e);
-        assert_eq!(&circuits, &vec![[a, b].into(), [c, d, e, f].into()]);
+    #[test]
+    fn test_circuits() {
+        let coords = small_coords();
+        let [a, b, c, d, e, f] = &coords;
 
-        let mut circuits = vec![[a, b, c].into(), [d, e].into(), [f].into()];
-        connect_junction_box(&mut circuits, a, b);
+        let mut circuits = Circuits::new(&coords);
+        assert_eq!(circuits.count(), 6);
+
+        assert!(circuits.connect(&Line3D(*a, *b)));
+        assert!(circuits.connect(&Line3D(*c, *a)));
         assert_eq!(
-            &circuits,
-            &vec![[a, b, c].into(), [d, e].into(), [f].into()]
+            circuits.sets(),
+            vec![[a, b, c].into(), [d].into(), [e].into(), [f].into()]
         );
+
+        assert!(circuits.connect(&Line3D(*f, *e)));
+        assert!(circuits.connect(&Line3D(*e, *a)));
+        assert_eq!(circuits.sets(), vec![[a, b, c, e, f].into(), [d].into()]);
+
+        // Same circuit
+        assert!(!circuits.connect(&Line3D(*b, *f)));
+        assert_eq!(circuits.count(), 2);
+
+        assert!(circuits.connect(&Line3D(*d, *c)));
+        assert_eq!(circuits.sets(), vec![[a, b, c, d, e, f].into()]);
+        assert_eq!(circuits.count(), 1);
     }
 
     #[test]
     fn test_connect_junction_boxes() {
-        let coords = [
-            Coords3D::new(2, 2, 0),
-            Coords3D::new(4, 2, 0),
-            Coords3D::new(2, 5, 0),
-            Coords3D::new(6, 6, 0),
-            Coords3D::new(9, 5, 0),
-            Coords3D::new(10, 0, 0),
-        ];
+        let coords = small_coords();
         let [a, b, c, d, e, f] = coords;
 
         // AB (len=2)
@@ -277,61 +507,211 @@ mod tests {
         assert_eq!(circuits.1, Some(Line3D(e, f)));
     }
 
+    #[test]
+    fn test_connect_junction_boxes_by_merges() {
+        let coords = small_coords();
+        let [a, b, c, d, e, f] = coords;
+
+        // Same as `connect_junction_boxes` before any line joins boxes in the same circuit
+        for rounds in 0..=3 {
+            assert_eq!(
+                connect_junction_boxes_by_merges(&coords, rounds),
+                connect_junction_boxes(&coords, rounds)
+            );
+        }
+
+        // 4 shortest lines: AB -> AC -> DE -> BC (no-op)
+        let circuits = connect_junction_boxes(&coords, 4);
+        assert_eq!(
+            circuits.0,
+            vec![[&a, &b, &c].into(), [&d, &e].into(), [&f].into()]
+        );
+
+        // 4 merges: AB -> AC -> DE -> (skip BC) -> BD
+        let circuits = connect_junction_boxes_by_merges(&coords, 4);
+        assert_eq!(circuits.0, vec![[&a, &b, &c, &d, &e].into(), [&f].into()]);
+        assert!(circuits.1.is_none());
+
+        // 5 merges join all 6 boxes
+        let circuits = connect_junction_boxes_by_merges(&coords, 5);
+        assert_eq!(circuits.0, vec![[&a, &b, &c, &d, &e, &f].into()]);
+        assert_eq!(circuits.1, Some(Line3D(e, f)));
+        assert_eq!(connect_junction_boxes_by_merges(&coords, 1000), circuits);
+    }
+
+    #[test]
+    fn test_connect_junction_boxes_tie_break() {
+        // AB and AC both have length 1. AC is connected first since `C < B` in canonical ordering.
+        //
+        // C
+        // A B
+        let [a, b, c] = [
+            Coords3D::new(0, 0, 0),
+            Coords3D::new(1, 0, 0),
+            Coords3D::new(0, 1, 0),
+        ];
+        let coords = [a, b, c];
+
+        // `HashMap` iteration order is random, so repeat to ensure it's not affected
+        for _ in 0..20 {
+            let circuits = connect_junction_boxes(&coords, 1);
+            assert_eq!(circuits.0, vec![[&a, &c].into(), [&b].into()]);
+
+            let circuits = connect_junction_boxes(&coords, 1000);
+            assert_eq!(circuits.1, Some(Line3D(a, b)));
+        }
+    }
+
     #[test]
     fn test_solve_day08_part_1() {
         // Puzzle example
-        let input = r"
-162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689"
-            .trim();
-        let coords = parse_input_to_coords(input);
+        let coords = example_coords();
         assert_eq!(solve_day08_part_1(&coords, 10), 5 * 4 * 2);
+
+        // Sorting by squared length connects in the same order
+        assert_eq!(
+            sorted_edges_by_squared_length(&coords)
+                .into_iter()
+                .map(|(line, _)| line)
+                .collect_vec(),
+            sorted_edges(&coords)
+                .into_iter()
+                .map(|(line, _)| line)
+                .collect_vec()
+        );
+        for rounds in [1, 10, 1000] {
+            assert_eq!(
+                connect_junction_boxes_by_squared_length(&coords, rounds),
+                connect_junction_boxes(&coords, rounds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_total_wire_length() {
+        let coords = small_coords();
+        assert_eq!(total_wire_length(&coords, 0), 0.0);
+        assert_eq!(total_wire_length(&coords, 2), 2.0 + 3.0); // AB -> AC
+        // AB -> AC -> DE -> BC (same circuit)
+        assert_eq!(total_wire_length(&coords, 4), 2.0 + 3.0 + 10_f64.sqrt());
+
+        // Puzzle example
+        let coords = example_coords();
+
+        // The first 4 connections are documented in the puzzle, where the 4th one joins 2 boxes
+        // already in the same circuit
+        let connected_lines = [
+            Line3D(Coords3D::new(162, 817, 812), Coords3D::new(425, 690, 689)),
+            Line3D(Coords3D::new(162, 817, 812), Coords3D::new(431, 825, 988)),
+            Line3D(Coords3D::new(906, 360, 560), Coords3D::new(805, 96, 715)),
+        ];
+        let expected: f64 = connected_lines.iter().map(|line| line.len()).sum();
+        assert_eq!(total_wire_length(&coords, 4), expected);
+    }
+
+    #[test]
+    fn test_isolated_boxes() {
+        let coords = small_coords();
+        let [a, b, c, d, e, f] = coords;
+
+        assert_eq!(isolated_boxes(&coords, 0), vec![a, b, c, d, e, f]);
+        assert_eq!(isolated_boxes(&coords, 1), vec![c, d, e, f]); // AB
+        assert_eq!(isolated_boxes(&coords, 3), vec![f]); // AB -> AC -> DE
+        assert_eq!(isolated_boxes(&coords, 1000), vec![]);
+
+        // Puzzle example
+        let coords = example_coords();
+        assert_eq!(
+            isolated_boxes(&coords, 10),
+            vec![
+                Coords3D::new(57, 618, 57),
+                Coords3D::new(592, 479, 940),
+                Coords3D::new(352, 342, 300),
+                Coords3D::new(466, 668, 158),
+                Coords3D::new(542, 29, 236),
+                Coords3D::new(216, 146, 977),
+                Coords3D::new(970, 615, 88),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nearest_neighbor_graph() {
+        let coords = example_coords();
+        assert_eq!(
+            nearest_neighbor_graph(&coords),
+            vec![
+                Line3D::new((162, 817, 812), (425, 690, 689)),
+                Line3D::new((57, 618, 57), (466, 668, 158)),
+                Line3D::new((906, 360, 560), (805, 96, 715)),
+                Line3D::new((592, 479, 940), (425, 690, 689)),
+                Line3D::new((352, 342, 300), (542, 29, 236)),
+                Line3D::new((466, 668, 158), (352, 342, 300)),
+                Line3D::new((431, 825, 988), (162, 817, 812)),
+                Line3D::new((739, 650, 466), (906, 360, 560)),
+                Line3D::new((52, 470, 668), (117, 168, 530)),
+                Line3D::new((216, 146, 977), (117, 168, 530)),
+                Line3D::new((819, 987, 18), (941, 993, 340)),
+                Line3D::new((346, 949, 466), (425, 690, 689)),
+                Line3D::new((970, 615, 88), (819, 987, 18)),
+                Line3D::new((862, 61, 35), (984, 92, 344)),
+            ]
+        );
+
+        assert_eq!(nearest_neighbor_graph(&coords[..1]), vec![]);
+        assert_eq!(nearest_neighbor_graph(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_merge_events() {
+        let coords = small_coords();
+        let [a, b, c, d, e, f] = coords;
+
+        // BC (len=3.6) is skipped since B and C are already in the same circuit
+        assert_eq!(
+            merge_events(&coords),
+            vec![
+                (Line3D(a, b), 5),
+                (Line3D(a, c), 4),
+                (Line3D(d, e), 3),
+                (Line3D(c, d), 2),
+                (Line3D(e, f), 1),
+            ]
+        );
+        assert_eq!(merge_events(&[a]), vec![]);
+        assert_eq!(merge_events(&[]), vec![]);
+
+        // Puzzle example
+        let coords = example_coords();
+        let events = merge_events(&coords);
+
+        assert_eq!(events.len(), coords.len() - 1);
+        assert!(
+            events
+                .iter()
+                .tuple_windows()
+                .all(|((_, prev_count), (_, count))| count < prev_count)
+        );
+        let (final_line, final_count) = events.last().unwrap();
+        assert_eq!(*final_count, 1);
+        assert_eq!(
+            Some(final_line.clone()),
+            connect_junction_boxes(&coords, usize::MAX).1
+        );
     }
 
     #[test]
     fn test_solve_day08_part_2() {
         // Puzzle example
-        let input = r"
-162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689"
-            .trim();
-        let coords = parse_input_to_coords(input);
+        let coords = example_coords();
         assert_eq!(solve_day08_part_2(&coords), 216 * 117);
     }
+
+    #[test]
+    fn test_solve_day08_empty_input() {
+        assert_eq!(solve_day08("", Part::One).unwrap(), 0);
+        assert_eq!(solve_day08("", Part::Two).unwrap(), 0);
+        assert_eq!(solve_day08("1,2,3", Part::Two).unwrap(), 0);
+        assert_eq!(connect_junction_boxes(&[], 1000), (vec![], None));
+    }
 }
@@ -1,12 +1,15 @@
-use std::{fmt::Display, fs};
+use std::{collections::HashSet, fmt::Display};
 
 use advent_of_code_2025::{
     Part,
     coords::{CompressedCoords2D, Coords2D},
+    grid::{all_filled_rectangles, flood_fill, grid_to_string, label_regions},
+    input::read_input,
+    iter::{max_by_key_or, unordered_pairs},
 };
 use anyhow::{Error, Result};
 use grid::Grid;
-use itertools::Itertools;
+use itertools::{Itertools, MinMaxResult};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Cell {
@@ -48,12 +51,50 @@ impl TryFrom<char> for Cell {
     }
 }
 
-fn solve_day09(input: &str, part: Part) -> usize {
-    let coords = parse_input_to_coords(input);
-    match part {
+/// Winding order of a polygon's vertices, as seen with the origin `(0, 0)` at the top left corner
+/// (i.e. y axis increases downward).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Winding {
+    Clockwise,
+    CounterClockwise,
+    /// The polygon has zero area (e.g. fewer than 3 vertices, or all vertices are on a line).
+    Degenerate,
+}
+
+/// Finds the winding order of the polygon formed by `coords` using the sign of its area from the
+/// [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula).
+fn polygon_winding(coords: &[Coords2D]) -> Winding {
+    let coords_iter = coords.iter().chain(coords.iter().take(1));
+    let double_signed_area: i128 = coords_iter
+        .tuple_windows()
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum();
+
+    // Positive area is counter-clockwise when y axis increases upward, so it's flipped here
+    match double_signed_area.signum() {
+        1 => Winding::Clockwise,
+        -1 => Winding::CounterClockwise,
+        _ => Winding::Degenerate,
+    }
+}
+
+/// Returns the `coords` in clockwise order, reversing them if they're given counter-clockwise.
+#[allow(dead_code)]
+fn normalize_winding(coords: &[Coords2D]) -> Vec<Coords2D> {
+    match polygon_winding(coords) {
+        Winding::CounterClockwise => coords.iter().rev().cloned().collect(),
+        Winding::Clockwise | Winding::Degenerate => coords.to_vec(),
+    }
+}
+
+/// Returns `0` if no rectangle can be formed (e.g. empty input).
+fn solve_day09(input: &str, part: Part) -> Result<usize> {
+    let coords = parse_input_to_coords(input)?;
+    let area = match part {
         Part::One => find_largest_rect_area(&coords),
         Part::Two => find_largest_red_and_green_rect_area(&coords),
-    }
+    };
+    Ok(area.unwrap_or_default())
 }
 
 #[inline]
@@ -62,16 +103,11 @@ fn rect_area(a: &Coords2D, b: &Coords2D) -> usize {
 }
 
 /// Part One - Finds the largest rectangle area formed from 2 coordinates being the corners of the rectangle.
-fn find_largest_rect_area(coords: &[Coords2D]) -> usize {
-    coords
-        .iter()
-        .combinations(2)
-        .map(|points| {
-            let [a, b] = [points[0], points[1]];
-            rect_area(a, b)
-        })
-        .max()
-        .expect("coords should not be empty")
+///
+/// Returns `None` if there are fewer than 2 coordinates.
+fn find_largest_rect_area(coords: &[Coords2D]) -> Option<usize> {
+    let areas = unordered_pairs(coords).map(|(a, b)| rect_area(a, b));
+    max_by_key_or(areas, |&area| area)
 }
 
 /// Part Two - The input `coords` of red tiles (`#`) can be connected in straight line by green
@@ -96,57 +132,218 @@ fn find_largest_rect_area(coords: &[Coords2D]) -> usize {
 ///
 /// # Algorithm
 ///
-/// 1. Compress the input coordinates from `max(x) * max(y)` to `len(unique(x)) * len(unique(y))` so
-///    that the board is significantly smaller to operate on
+/// 1. Compress the input coordinates from `max(x) * max(y)` to about `len(unique(x)) * len(unique(y))`
+///    so that the board is significantly smaller to operate on, while keeping a gap between
+///    non-consecutive values (see [CompressedCoords2D::from_coords_with_gaps])
 /// 2. Connect the red tiles (`#`) together with green tiles (`X`) to form an enclosed polygon
-/// 3. Find a point that's inside the polygon
-/// 4. Fill the polygon with green tiles starting with the point in Step 3
-/// 5. For every 2 pairs of red tiles, see if the 4 sides of rectangle are entirely red/green tiles
+/// 3. Find a point that's inside the polygon (see [find_first_inside_point])
+/// 4. Fill the polygon with green tiles starting with the point in Step 3, and repeat from Step 3
+///    until no empty tile is left inside the polygon
+/// 5. Find the largest rectangle with red tile corners inside the filled polygon (see
+///    [largest_red_green_rect_weighted])
 ///
 /// Inspired by https://www.reddit.com/r/adventofcode/comments/1pichj2/comment/nt5guy3
 ///
-/// Limitation: Step 4 only fills the polygon once from one starting point. However, it's possible
-/// that any polygon has >=2 areas of empty tiles that are disconnected from each other and can be filled.
-/// The puzzle input doesn't have this edge case so it's fine.
-fn find_largest_red_and_green_rect_area(coords: &[Coords2D]) -> usize {
-    let compressed_coords = CompressedCoords2D::from_coords(coords);
+/// A polygon can have >=2 regions of empty tiles inside that are disconnected from each other, so
+/// Step 3 and 4 are repeated rather than filling from a single inside point.
+///
+/// Returns `None` if no satisfying rectangle exists (e.g. fewer than 2 coordinates).
+fn find_largest_red_and_green_rect_area(coords: &[Coords2D]) -> Option<usize> {
+    let compressed_coords = CompressedCoords2D::from_coords_with_gaps(coords);
+    let grid = make_filled_cell_grid(&compressed_coords);
 
-    let mut grid = make_cell_grid_from_compressed_coords(&compressed_coords);
-    connect_red_tiles(&mut grid, &compressed_coords.coords);
+    match largest_red_green_rect_weighted(&compressed_coords, &grid) {
+        0 => None,
+        area => Some(area),
+    }
+}
+
+/// Finds the area (in original coordinates) of the largest rectangle that has red tiles on 2
+/// opposite corners and consists of only red/green tiles. Returns `0` if there's no such rectangle.
+///
+/// Any such rectangle lies inside a maximal all-red/green rectangle of the compressed `grid`, and any
+/// 2 red tiles inside the same maximal rectangle form a valid rectangle. So instead of checking the
+/// sides of every pair of red tiles, it sweeps the maximal rectangles (see [all_filled_rectangles])
+/// and only pairs up red tiles within each of them.
+///
+/// Each maximal rectangle is weighted by the original span of its compressed cells, which is an upper
+/// bound of the area of any rectangle inside it. Rectangles are visited from the largest bound so the
+/// sweep stops once no remaining rectangle can beat the best area found.
+fn largest_red_green_rect_weighted(compressed: &CompressedCoords2D, grid: &Grid<Cell>) -> usize {
+    // A gap from `CompressedCoords2D::from_coords_with_gaps` has no red tiles, so the span is
+    // shrunk inward to the next compressed value that has an original value. `None` if there's no
+    // such value next to the gap.
+    let to_original = |row: usize, col: usize, step: i64| {
+        let (row, col) = (row as i64, col as i64);
+        [(0, 0), (step, 0), (0, step), (step, step)]
+            .iter()
+            .find_map(|&(dx, dy)| compressed.to_original(&Coords2D::new(col + dx, row + dy)))
+    };
 
-    if let Some(start_coords) = find_first_inside_point(&grid) {
-        fill_green_tiles(&mut grid, &start_coords);
+    // A rectangle without an original value on its corners only covers gaps, so it has no red tiles
+    let mut weighted_rects: Vec<_> = all_filled_rectangles(grid, Cell::is_tile)
+        .into_iter()
+        .filter_map(|(top_left, bottom_right, _)| {
+            let weighted_area = rect_area(
+                &to_original(top_left.0, top_left.1, 1)?,
+                &to_original(bottom_right.0, bottom_right.1, -1)?,
+            );
+            Some((weighted_area, top_left, bottom_right))
+        })
+        .collect();
+    weighted_rects.sort_by_key(|&(weighted_area, _, _)| std::cmp::Reverse(weighted_area));
+
+    let mut largest_area = 0;
+    for (weighted_area, (top, left), (bottom, right)) in weighted_rects {
+        if weighted_area <= largest_area {
+            break;
+        }
+        let red_tiles: Vec<Coords2D> = compressed
+            .coords
+            .iter()
+            .filter(|c| (left..=right).contains(&(c.x as usize)))
+            .filter(|c| (top..=bottom).contains(&(c.y as usize)))
+            .map(|c| compressed.to_original(c).unwrap())
+            .collect();
+        let area = unordered_pairs(&red_tiles)
+            .map(|(a, b)| rect_area(a, b))
+            .max()
+            .unwrap_or_default();
+        largest_area = largest_area.max(area);
     }
+    largest_area
+}
 
-    compressed_coords
-        .coords
-        .iter()
-        .combinations(2)
-        .filter_map(|points| {
-            let [a, b] = [points[0], points[1]];
-            match is_rect_in_red_and_green(&grid, a, b) {
-                true => {
-                    let a_original = compressed_coords.to_original(a).unwrap();
-                    let b_original = compressed_coords.to_original(b).unwrap();
-                    Some(rect_area(&a_original, &b_original))
-                }
-                false => None,
+/// Finds the same area as [largest_red_green_rect_weighted] by checking the sides of every pair of
+/// red tiles. Returns `None` if there's no such rectangle.
+#[allow(dead_code)]
+fn largest_red_green_rect_by_pairs(
+    compressed_coords: &CompressedCoords2D,
+    grid: &Grid<Cell>,
+) -> Option<usize> {
+    unordered_pairs(&compressed_coords.coords)
+        .filter_map(|(a, b)| match is_rect_in_red_and_green(grid, a, b) {
+            true => {
+                let a_original = compressed_coords.to_original(a).unwrap();
+                let b_original = compressed_coords.to_original(b).unwrap();
+                Some(rect_area(&a_original, &b_original))
             }
+            false => None,
         })
         .max()
-        .expect("should have at least 1 satisfying rectangle")
 }
 
-fn parse_input_to_coords(input: &str) -> Vec<Coords2D> {
+/// Counts the number of pairs of red tiles that form a rectangle consisting of only red and green
+/// tiles. Uses the same steps as [find_largest_red_and_green_rect_area].
+#[allow(dead_code)]
+fn count_valid_rectangles(coords: &[Coords2D]) -> usize {
+    let compressed_coords = CompressedCoords2D::from_coords_with_gaps(coords);
+    let grid = make_filled_cell_grid(&compressed_coords);
+
+    unordered_pairs(&compressed_coords.coords)
+        .filter(|(a, b)| is_rect_in_red_and_green(&grid, a, b))
+        .count()
+}
+
+/// Computes how many times smaller the compressed grid is compared to the original grid, i.e. the
+/// area of the bounding box of `coords` divided by the area of the compressed grid. A ratio near `1`
+/// means compression barely helps.
+///
+/// Returns `1.0` if `coords` is empty.
+#[allow(dead_code)]
+fn compression_ratio(coords: &[Coords2D]) -> f64 {
+    if coords.is_empty() {
+        return 1.0;
+    }
+    let bounds = |values: MinMaxResult<i64>| values.into_option().expect("coords is not empty");
+    let (min_x, max_x) = bounds(coords.iter().map(|c| c.x).minmax());
+    let (min_y, max_y) = bounds(coords.iter().map(|c| c.y).minmax());
+    let original_area = (max_x.abs_diff(min_x) + 1) as f64 * (max_y.abs_diff(min_y) + 1) as f64;
+
+    let compressed_coords = CompressedCoords2D::from_coords(coords);
+    let compressed_area =
+        ((compressed_coords.max_x() + 1) * (compressed_coords.max_y() + 1)) as f64;
+
+    original_area / compressed_area
+}
+
+/// Constructs the compressed cell grid where the polygon formed by the red tiles is filled with
+/// green tiles.
+fn make_filled_cell_grid(compressed_coords: &CompressedCoords2D) -> Grid<Cell> {
+    let mut grid = make_cell_grid_from_compressed_coords(compressed_coords);
+    connect_red_tiles(&mut grid, &compressed_coords.coords);
+    fill_interior_regions(&mut grid);
+    grid
+}
+
+/// Fills the polygon with green tiles from [find_first_inside_point] until no empty tile is left
+/// inside, so that every disconnected region inside the polygon is filled.
+fn fill_interior_regions(grid: &mut Grid<Cell>) {
+    while let Some(start) = find_first_inside_point(grid) {
+        fill_green_tiles(grid, &start);
+    }
+}
+
+/// Runs the steps of [make_filled_cell_grid] on the red tiles like
+/// [find_largest_red_and_green_rect_area] does, and returns the compressed grid as strings (see
+/// [grid_to_string]) right after [connect_red_tiles] and after [fill_interior_regions]. Useful for
+/// debugging the filling step.
+///
+/// # Example
+///
+/// The puzzle example gives:
+///
+/// ```txt
+/// ..#XXX#                 ..#XXX#
+/// ..X...X                 ..XXXXX
+/// #X#...X                 #X#XXXX
+/// X.....X    ==fill==>    XXXXXXX
+/// #XXX#.X                 #XXX#XX
+/// ....X.X                 ....XXX
+/// ....#X#                 ....#X#
+/// ```
+#[allow(dead_code)]
+fn debug_fill_steps(coords: &[Coords2D]) -> (String, String) {
+    let compressed_coords = CompressedCoords2D::from_coords_with_gaps(coords);
+    let mut grid = make_cell_grid_from_compressed_coords(&compressed_coords);
+
+    connect_red_tiles(&mut grid, &compressed_coords.coords);
+    let connected = grid_to_string(&grid);
+
+    fill_interior_regions(&mut grid);
+    let filled = grid_to_string(&grid);
+
+    (connected, filled)
+}
+
+/// Checks that every red tile lies on the boundary of the filled polygon, i.e. it has at least one
+/// neighbor that's empty or outside the grid. A red tile strictly inside the polygon means the tiles
+/// were connected or filled incorrectly.
+///
+/// Diagonal neighbors are included because a red tile at a concave corner only touches the outside
+/// diagonally:
+///
+/// ```txt
+/// #XXX#
+/// XXXXX
+/// XX#X#   <-- this `#` has tiles on all 4 sides
+/// XXX..
+/// #X#..
+/// ```
+#[allow(dead_code)]
+fn verify_red_tiles_on_boundary(grid: &Grid<Cell>, red_coords: &[Coords2D]) -> bool {
+    red_coords.iter().all(|coords| {
+        coords.neighbors_8().iter().any(|neighbor| {
+            grid.get(neighbor.y, neighbor.x)
+                .is_none_or(|cell| !cell.is_tile())
+        })
+    })
+}
+
+fn parse_input_to_coords(input: &str) -> Result<Vec<Coords2D>> {
     input
         .lines()
-        .map(|line| {
-            let values: Vec<i64> = line
-                .split(",")
-                .map(|num_str| num_str.parse().expect("should be a valid integer"))
-                .collect();
-            Coords2D::new(values[0], values[1])
-        })
+        .map(|line| Ok(line.parse::<Coords2D>()?))
         .collect()
 }
 
@@ -181,18 +378,13 @@ fn connect_red_tiles(grid: &mut Grid<Cell>, red_tile_coords: &[Coords2D]) {
     // Self-wraps with first element (e.g. A -> B -> C -> A)
     let coords_iter = red_tile_coords.iter().chain(red_tile_coords.iter().take(1));
     for (a, b) in coords_iter.tuple_windows() {
-        if a.x == b.x {
-            let (start, end) = if a.y < b.y { (a, b) } else { (b, a) };
-            for y in (start.y + 1)..end.y {
-                if let Some(cell) = grid.get_mut(y, start.x) {
-                    *cell = Cell::Green;
-                }
-            }
-        }
-        if a.y == b.y {
-            let (start, end) = if a.x < b.x { (a, b) } else { (b, a) };
-            for x in (start.x + 1)..end.x {
-                if let Some(cell) = grid.get_mut(start.y, x) {
+        let Some(points) = a.line_to(b) else {
+            continue;
+        };
+        // Only fill the points between the two red tiles
+        if let [_, in_between @ .., _] = points.as_slice() {
+            for point in in_between {
+                if let Some(cell) = grid.get_mut(point.y, point.x) {
                     *cell = Cell::Green;
                 }
             }
@@ -201,78 +393,104 @@ fn connect_red_tiles(grid: &mut Grid<Cell>, red_tile_coords: &[Coords2D]) {
 }
 
 /// Finds the first empty point that's inside the polygon after connecting red tiles together to form
-/// edges. The search starts from top to bottom, left to right.
+/// edges, which is the top-left cell of the first region from [interior_regions].
 ///
 /// It uses the [Point in Polygon](https://en.wikipedia.org/wiki/Point_in_polygon) algorithm, which
 /// casts a horizontal ray from left to the point. The theorem states that the point is inside if the
 /// ray intersects the edges for odd number of times.
 fn find_first_inside_point(grid: &Grid<Cell>) -> Option<Coords2D> {
-    // No need search first and last row/col since it's guaranteed to be outside the polygon
-    for row in 1..grid.rows() - 1 {
-        for col in 1..grid.cols() - 1 {
-            if grid[(row, col)] != Cell::Empty {
-                continue;
-            }
+    let region = interior_regions(grid).into_iter().next()?;
+    let (row, col) = *region.iter().min().expect("region should not be empty");
+    Some(Coords2D::new(col as i64, row as i64))
+}
 
-            // When found empty cell, cast ray leftwards and count no. of boundary crossings
-            let mut boundary_cross_indexes: Vec<usize> = vec![];
-            let mut inside_boundary = false;
-
-            for x in (0..col).rev() {
-                let cell = grid[(row, x)];
-                if cell.is_tile() && !inside_boundary {
-                    boundary_cross_indexes.push(x);
-                    inside_boundary = true;
-                } else if !cell.is_tile() && inside_boundary {
-                    // Handle `.#XXX#.`
-                    //       x ┘     └ start
-                    if grid[(row, x + 1)] == Cell::Red {
-                        boundary_cross_indexes.push(x + 1);
-                    }
-
-                    // Handle `.XXX.`
-                    //       x ┘   └ start
-                    // If all are `X`, then they must be vertical edges
-                    let last_boundary_cross_index = *boundary_cross_indexes.last().unwrap();
-                    let boundary_cells: Vec<&Cell> = grid
-                        .iter_row(row)
-                        .get(x + 1..=last_boundary_cross_index)
-                        .collect();
-                    if boundary_cells.iter().all(|&cell| *cell == Cell::Green) {
-                        let mut boundary_indexes: Vec<usize> =
-                            (x + 1..=last_boundary_cross_index - 1).rev().collect();
-                        boundary_cross_indexes.append(&mut boundary_indexes);
-                    }
-
-                    inside_boundary = false;
-                }
+/// Checks whether the cell at `(row, col)` is inside the polygon, using the point in polygon
+/// algorithm described in [find_first_inside_point].
+fn is_inside_polygon(grid: &Grid<Cell>, row: usize, col: usize) -> bool {
+    // Cast ray leftwards and count no. of boundary crossings
+    let mut boundary_cross_indexes: Vec<usize> = vec![];
+    let mut inside_boundary = false;
+
+    for x in (0..col).rev() {
+        let cell = grid[(row, x)];
+        if cell.is_tile() && !inside_boundary {
+            boundary_cross_indexes.push(x);
+            inside_boundary = true;
+        } else if !cell.is_tile() && inside_boundary {
+            // Handle `.#XXX#.`
+            //       x ┘     └ start
+            if grid[(row, x + 1)] == Cell::Red {
+                boundary_cross_indexes.push(x + 1);
             }
 
-            if boundary_cross_indexes.len() % 2 == 1 {
-                return Some(Coords2D::new(col as i64, row as i64));
+            // Handle `.XXX.`
+            //       x ┘   └ start
+            // If all are `X`, then they must be vertical edges
+            let last_boundary_cross_index = *boundary_cross_indexes.last().unwrap();
+            let boundary_cells: Vec<&Cell> = grid
+                .iter_row(row)
+                .get(x + 1..=last_boundary_cross_index)
+                .collect();
+            if boundary_cells.iter().all(|&cell| *cell == Cell::Green) {
+                let mut boundary_indexes: Vec<usize> =
+                    (x + 1..=last_boundary_cross_index - 1).rev().collect();
+                boundary_cross_indexes.append(&mut boundary_indexes);
             }
+
+            inside_boundary = false;
         }
     }
-    None
+
+    boundary_cross_indexes.len() % 2 == 1
+}
+
+/// Finds every connected region of empty cells that is inside the polygon after connecting red tiles
+/// together to form edges.
+///
+/// Regions touching the border of the grid are always outside the polygon. Otherwise, the top-left
+/// cell of each region is checked with [is_inside_polygon].
+fn interior_regions(grid: &Grid<Cell>) -> Vec<HashSet<(usize, usize)>> {
+    let is_border = |(row, col): (usize, usize)| {
+        row == 0 || col == 0 || row == grid.rows() - 1 || col == grid.cols() - 1
+    };
+
+    empty_regions(grid)
+        .into_iter()
+        .filter(|region| {
+            let top_left = *region.iter().min().expect("region should not be empty");
+            !region.iter().any(|&coords| is_border(coords))
+                && is_inside_polygon(grid, top_left.0, top_left.1)
+        })
+        .collect()
+}
+
+/// Finds every 4-directionally connected region of empty cells, as sets of `(row, col)`. Regions are
+/// ordered by their first cell in row-major order.
+fn empty_regions(grid: &Grid<Cell>) -> Vec<HashSet<(usize, usize)>> {
+    let labels = label_regions(grid, |&cell| cell == Cell::Empty);
+    let mut regions: Vec<HashSet<(usize, usize)>> = vec![];
+    for (coords, &id) in labels.indexed_iter() {
+        let Some(id) = id else {
+            continue;
+        };
+        if id == regions.len() {
+            regions.push(HashSet::new());
+        }
+        regions[id].insert(coords);
+    }
+    regions
 }
 
 /// Fills the polygon created from connecting red tiles (`#`) with green tiles (`X`).
 fn fill_green_tiles(grid: &mut Grid<Cell>, start: &Coords2D) {
     assert_eq!(grid.get(start.y, start.x), Some(&Cell::Empty));
 
-    let mut coords_to_fill: Vec<Coords2D> = vec![start.clone()];
-    let search_dirs = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-
-    while let Some(coords) = coords_to_fill.pop() {
-        *grid.get_mut(coords.y, coords.x).unwrap() = Cell::Green;
-
-        search_dirs.iter().for_each(|(dx, dy)| {
-            let new_coords = Coords2D::new(coords.x + dx, coords.y + dy);
-            if grid.get(new_coords.y, new_coords.x) == Some(&Cell::Empty) {
-                coords_to_fill.push(new_coords);
-            }
-        });
-    }
+    flood_fill(
+        grid,
+        (start.y as usize, start.x as usize),
+        |cell| *cell == Cell::Empty,
+        |cell| *cell = Cell::Green,
+    );
 }
 
 /// Checks whether the rectangle formed by the two coordinates are entirely consisted of red/green tiles.
@@ -303,11 +521,11 @@ fn is_rect_in_red_and_green(grid: &Grid<Cell>, a: &Coords2D, b: &Coords2D) -> bo
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day09.txt")?;
+    let input = read_input("puzzle_inputs/day09.txt")?;
     let input = input.trim();
 
-    let part_1_solution = solve_day09(input, Part::One);
-    let part_2_solution = solve_day09(input, Part::Two);
+    let part_1_solution = solve_day09(input, Part::One)?;
+    let part_2_solution = solve_day09(input, Part::Two)?;
     println!("Part 1 Solution: {part_1_solution}");
     println!("Part 2 Solution: {part_2_solution}");
     Ok(())
@@ -316,32 +534,93 @@ fn main() -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code_2025::grid::{grid_to_string, parse_string_to_grid};
+    use advent_of_code_2025::grid::parse_string_to_grid;
     use grid::grid;
     use pretty_assertions::assert_eq;
 
+    /// Puzzle example.
+    const EXAMPLE_INPUT: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+
+    fn example_coords() -> Vec<Coords2D> {
+        parse_input_to_coords(EXAMPLE_INPUT).unwrap()
+    }
+
     #[test]
     fn test_parse_input_to_coords() {
         let input = "162,817\n57,618";
         assert_eq!(
-            parse_input_to_coords(input),
+            parse_input_to_coords(input).unwrap(),
             vec![Coords2D::new(162, 817), Coords2D::new(57, 618)]
-        )
+        );
+
+        assert_eq!(parse_input_to_coords("").unwrap(), vec![]);
+        assert!(parse_input_to_coords("162").is_err());
+        assert!(parse_input_to_coords("162,817,812").is_err());
+        assert!(parse_input_to_coords("162,abc").is_err());
     }
 
     #[test]
-    fn test_find_largest_rect_area() {
-        let coords = [
-            Coords2D::new(7, 1),
-            Coords2D::new(11, 1),
-            Coords2D::new(11, 7),
-            Coords2D::new(9, 7),
-            Coords2D::new(9, 5),
-            Coords2D::new(2, 5),
-            Coords2D::new(2, 3),
-            Coords2D::new(7, 3),
+    fn test_solve_day09() {
+        assert_eq!(solve_day09(EXAMPLE_INPUT, Part::One).unwrap(), 50);
+        assert_eq!(solve_day09(EXAMPLE_INPUT, Part::Two).unwrap(), 24);
+
+        assert_eq!(solve_day09("", Part::One).unwrap(), 0);
+        assert_eq!(solve_day09("", Part::Two).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_polygon_winding() {
+        // A -> B
+        //      |
+        // D <- C
+        let clockwise = [
+            Coords2D::new(0, 0),
+            Coords2D::new(2, 0),
+            Coords2D::new(2, 2),
+            Coords2D::new(0, 2),
         ];
-        assert_eq!(find_largest_rect_area(&coords), 50);
+        assert_eq!(polygon_winding(&clockwise), Winding::Clockwise);
+        assert_eq!(normalize_winding(&clockwise), clockwise.to_vec());
+
+        // A <- D
+        // |
+        // B -> C
+        let counter_clockwise = [
+            Coords2D::new(0, 0),
+            Coords2D::new(0, 2),
+            Coords2D::new(2, 2),
+            Coords2D::new(2, 0),
+        ];
+        assert_eq!(
+            polygon_winding(&counter_clockwise),
+            Winding::CounterClockwise
+        );
+        assert_eq!(
+            polygon_winding(&normalize_winding(&counter_clockwise)),
+            Winding::Clockwise
+        );
+
+        assert_eq!(polygon_winding(&[]), Winding::Degenerate);
+        assert_eq!(
+            polygon_winding(&[Coords2D::new(0, 0), Coords2D::new(5, 0)]),
+            Winding::Degenerate
+        );
+
+        // Puzzle example is clockwise, and the answer doesn't depend on the winding
+        let coords = example_coords();
+        assert_eq!(polygon_winding(&coords), Winding::Clockwise);
+        let reversed: Vec<Coords2D> = coords.iter().rev().cloned().collect();
+        assert_eq!(polygon_winding(&reversed), Winding::CounterClockwise);
+        assert_eq!(find_largest_red_and_green_rect_area(&reversed), Some(24));
+    }
+
+    #[test]
+    fn test_find_largest_rect_area() {
+        let coords = example_coords();
+        assert_eq!(find_largest_rect_area(&coords), Some(50));
+
+        assert_eq!(find_largest_rect_area(&[]), None);
+        assert_eq!(find_largest_rect_area(&[Coords2D::new(7, 1)]), None);
     }
 
     #[test]
@@ -356,19 +635,10 @@ mod tests {
         // .........X.X..
         // .........#X#..
         // ..............
-        let coords = [
-            Coords2D::new(7, 1),
-            Coords2D::new(11, 1),
-            Coords2D::new(11, 7),
-            Coords2D::new(9, 7),
-            Coords2D::new(9, 5),
-            Coords2D::new(2, 5),
-            Coords2D::new(2, 3),
-            Coords2D::new(7, 3),
-        ];
-        assert_eq!(find_largest_red_and_green_rect_area(&coords), 24);
+        let coords = example_coords();
+        assert_eq!(find_largest_red_and_green_rect_area(&coords), Some(24));
 
-        // FIXME: This test case fails because the empty spaces are disconnected in 2 places:
+        // The empty spaces inside are disconnected in 2 places:
         //
         // Compressed Board before fill:
         //
@@ -402,7 +672,123 @@ mod tests {
             Coords2D::new(6, 9),
             Coords2D::new(1, 9),
         ];
-        // assert_eq!(find_largest_red_and_green_rect_area(&coords), 30);
+        assert_eq!(find_largest_red_and_green_rect_area(&coords), Some(30));
+    }
+
+    #[test]
+    fn test_largest_red_green_rect_weighted() {
+        // Puzzle example
+        let coords = example_coords();
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&coords);
+        let grid = make_filled_cell_grid(&compressed_coords);
+        assert_eq!(
+            largest_red_green_rect_weighted(&compressed_coords, &grid),
+            24
+        );
+        assert_eq!(
+            largest_red_green_rect_by_pairs(&compressed_coords, &grid),
+            Some(24)
+        );
+
+        // Staircase polygon going up and right with pseudo-random step sizes:
+        // (0, 0) -> (0, y1) -> (x1, y1) -> (x1, y2) -> ... -> (xn, yn) -> (xn, 0)
+        let mut seed: u64 = 42;
+        let mut next_step = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as i64 % 1000 + 1
+        };
+        let mut coords = vec![Coords2D::new(0, 0)];
+        let (mut x, mut y) = (0, 0);
+        for _ in 0..40 {
+            y -= next_step();
+            coords.push(Coords2D::new(x, y));
+            x += next_step();
+            coords.push(Coords2D::new(x, y));
+        }
+        coords.push(Coords2D::new(x, 0));
+
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&coords);
+        let grid = make_filled_cell_grid(&compressed_coords);
+        let area = largest_red_green_rect_weighted(&compressed_coords, &grid);
+        assert_eq!(area, 94786944);
+        assert_eq!(
+            largest_red_green_rect_by_pairs(&compressed_coords, &grid),
+            Some(area)
+        );
+    }
+
+    #[test]
+    fn test_debug_fill_steps() {
+        // Puzzle example
+        let coords = example_coords();
+        let (connected, filled) = debug_fill_steps(&coords);
+        let expected_connected = r"
+..#XXX#
+..X...X
+#X#...X
+X.....X
+#XXX#.X
+....X.X
+....#X#"
+            .trim();
+        let expected_filled = r"
+..#XXX#
+..XXXXX
+#X#XXXX
+XXXXXXX
+#XXX#XX
+....XXX
+....#X#"
+            .trim();
+        assert_eq!(connected, expected_connected);
+        assert_eq!(filled, expected_filled);
+
+        // Same grids in counter-clockwise order
+        let reversed: Vec<Coords2D> = coords.iter().rev().cloned().collect();
+        assert_eq!(
+            debug_fill_steps(&reversed),
+            (expected_connected.to_string(), expected_filled.to_string())
+        );
+    }
+
+    #[test]
+    fn test_count_valid_rectangles() {
+        // Puzzle example
+        let coords = example_coords();
+        assert_eq!(count_valid_rectangles(&coords), 16);
+
+        assert_eq!(count_valid_rectangles(&[]), 0);
+    }
+
+    #[test]
+    fn test_compression_ratio() {
+        // Sparse: 100_001 x 50_001 original grid vs 2 x 2 compressed grid
+        let coords = [
+            Coords2D::new(0, 0),
+            Coords2D::new(100_000, 0),
+            Coords2D::new(100_000, 50_000),
+            Coords2D::new(0, 50_000),
+        ];
+        assert_eq!(compression_ratio(&coords), 100_001.0 * 50_001.0 / 4.0);
+        assert!(compression_ratio(&coords) > 1e9);
+
+        // Dense: every x and y value is used, except x = 3
+        let coords = [
+            Coords2D::new(0, 0),
+            Coords2D::new(1, 1),
+            Coords2D::new(2, 2),
+            Coords2D::new(4, 2),
+        ];
+        assert_eq!(compression_ratio(&coords), (5.0 * 3.0) / (4.0 * 3.0));
+
+        // Puzzle example: 10 x 7 original grid vs 4 x 4 compressed grid
+        let coords = example_coords();
+        assert_eq!(compression_ratio(&coords), (10.0 * 7.0) / (4.0 * 4.0));
+
+        assert_eq!(compression_ratio(&[Coords2D::new(5, 5)]), 1.0);
+        assert_eq!(compression_ratio(&[]), 1.0);
     }
 
     #[test]
@@ -649,6 +1035,88 @@ XXXXXXXXXXXXX
         assert_eq!(grid_to_string(&grid), expected_grid_str);
     }
 
+    #[test]
+    fn test_interior_regions() {
+        // .#XO............#X#.
+        // .XXX............XXX.
+        // .XXX............XXX.
+        // .XXX............XXX.
+        // .XXX............XXX.
+        // .XXX............XXX.
+        // .XX#XXXXXXXXXXXX#XX.
+        // .XXXXX#XXXXXX#XXXXX.
+        // .XXXXXX......XXXXXX.
+        // .OXXXX#......#XXXX#.
+        let coords = [
+            Coords2D::new(1, 0),
+            Coords2D::new(3, 0),
+            Coords2D::new(3, 6),
+            Coords2D::new(16, 6),
+            Coords2D::new(16, 0),
+            Coords2D::new(18, 0),
+            Coords2D::new(18, 9),
+            Coords2D::new(13, 9),
+            Coords2D::new(13, 7),
+            Coords2D::new(6, 7),
+            Coords2D::new(6, 9),
+            Coords2D::new(1, 9),
+        ];
+        let compressed_coords = CompressedCoords2D::from_coords(&coords);
+        let mut grid = make_cell_grid_from_compressed_coords(&compressed_coords);
+        connect_red_tiles(&mut grid, &compressed_coords.coords);
+        assert_eq!(grid_to_string(&grid), "##..##\nX#XX#X\nX.##.X\n#X##X#");
+
+        assert_eq!(
+            interior_regions(&grid),
+            vec![HashSet::from([(2, 1)]), HashSet::from([(2, 4)])]
+        );
+
+        // With gaps, the notch at the bottom between `x = 6` and `x = 13` is kept, and it's outside
+        // since it reaches the border
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&coords);
+        let filled_grid = make_filled_cell_grid(&compressed_coords);
+        assert_eq!(filled_grid[(4, 5)], Cell::Empty);
+        assert_eq!(filled_grid[(5, 5)], Cell::Empty);
+        assert_eq!(interior_regions(&filled_grid), vec![]);
+
+        // The top region is outside
+        assert_eq!(
+            empty_regions(&grid),
+            vec![
+                HashSet::from([(0, 2), (0, 3)]),
+                HashSet::from([(2, 1)]),
+                HashSet::from([(2, 4)])
+            ]
+        );
+
+        // No interior regions left after filling
+        let grid =
+            make_filled_cell_grid(&CompressedCoords2D::from_coords_with_gaps(&example_coords()));
+        assert_eq!(interior_regions(&grid), vec![]);
+    }
+
+    #[test]
+    fn test_verify_red_tiles_on_boundary() {
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&example_coords());
+        let grid = make_filled_cell_grid(&compressed_coords);
+        assert!(verify_red_tiles_on_boundary(
+            &grid,
+            &compressed_coords.coords
+        ));
+
+        // Red tile in the middle of a filled area
+        let grid = parse_string_to_grid("#X#\nX#X\n#X#", Cell::try_from).unwrap();
+        let red_coords = [
+            Coords2D::new(0, 0),
+            Coords2D::new(2, 0),
+            Coords2D::new(1, 1),
+            Coords2D::new(2, 2),
+            Coords2D::new(0, 2),
+        ];
+        assert!(!verify_red_tiles_on_boundary(&grid, &red_coords));
+        assert!(verify_red_tiles_on_boundary(&grid, &red_coords[..1]));
+    }
+
     #[test]
     fn test_is_rect_in_red_and_green() {
         let input = r"
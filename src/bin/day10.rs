@@ -1,38 +1,49 @@
 use std::{
-    collections::{HashSet, VecDeque},
-    fs,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
 };
 
-use advent_of_code_2025::Part;
+use advent_of_code_2025::{Part, bitset::BulbState, input::read_input};
 use anyhow::Result;
 use itertools::Itertools;
 
-fn solve_day10(input: &str, part: Part) -> usize {
+/// Returns an error if the target state of any machine is unreachable.
+fn solve_day10(input: &str, part: Part) -> Result<usize> {
     let machines: Vec<Machine> = input.lines().map(Machine::from_input).collect();
-    match part {
-        Part::One => machines.iter().map(min_presses_to_target_state).sum(),
-        Part::Two => todo!(),
-    }
+    machines
+        .iter()
+        .map(|machine| {
+            solve_machine(machine, part).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Machine target state [{}] is unreachable",
+                    debug_machine_state(machine.target_state, machine.bulb_count)
+                )
+            })
+        })
+        .sum()
 }
 
 #[derive(Debug, Clone, PartialEq)]
 struct Machine {
     bulb_count: usize,
-    /// Target states of each bulb represented in binary. Leftmost bulb is least significant bit.
+    /// Target states of each bulb.
+    target_state: BulbState,
+    /// Each button is represented as the bulbs it toggles. Pressing the button toggles the bulbs
+    /// described by its positions.
     ///
-    /// e.g. `[##..]` = `0011` in binary (NOT `1100`!), or `6` in decimal
-    target_state: u16,
-    /// Each button is represented in binary. Pressing the button toggles the bulbs described by
-    /// its positions.
-    ///
-    /// e.g. `(0, 2)` = `0101` in binary, or `9` in decimal
-    buttons: Vec<u16>,
+    /// e.g. `(0, 2)` = `0101` in binary, or `5` in decimal
+    buttons: Vec<BulbState>,
     joltages: Vec<u16>,
 }
 
 impl Machine {
     #[must_use]
-    fn new(bulb_count: usize, target_state: u16, buttons: Vec<u16>, joltages: Vec<u16>) -> Self {
+    fn new(
+        bulb_count: usize,
+        target_state: BulbState,
+        buttons: Vec<BulbState>,
+        joltages: Vec<u16>,
+    ) -> Self {
         Self {
             bulb_count,
             target_state,
@@ -45,20 +56,20 @@ impl Machine {
     fn from_input(input: &str) -> Self {
         let segments: Vec<&str> = input.split_ascii_whitespace().collect();
 
-        let target_state_str = &segments[0][1..segments[0].len() - 1]
-            .chars()
-            .rev()
-            .map(|c| if c == '#' { '1' } else { '0' })
-            .join("");
+        let target_state_str = &segments[0][1..segments[0].len() - 1];
         let bulb_count = target_state_str.len();
-        let target_state = u16::from_str_radix(target_state_str, 2).unwrap();
+        let target_state = BulbState::from_pattern(target_state_str).unwrap();
 
         let button_strings = segments.iter().get(1..segments.len() - 1);
-        let buttons: Vec<u16> = button_strings
+        let buttons: Vec<BulbState> = button_strings
             .map(|input_str| {
-                input_str[1..input_str.len() - 1]
-                    .split(",")
-                    .fold(0, |acc, pos| acc | (1 << pos.parse::<u16>().unwrap()))
+                input_str[1..input_str.len() - 1].split(",").fold(
+                    BulbState::default(),
+                    |mut button, pos| {
+                        button.set(pos.parse().unwrap(), true);
+                        button
+                    },
+                )
             })
             .collect();
 
@@ -72,55 +83,342 @@ impl Machine {
     }
 }
 
+/// Total joltage of pressing the set of `buttons`, given as indices of [Machine::buttons]. Pressing
+/// button `i` costs `joltages[i]`.
+///
+/// Same as toggling, pressing a button twice is a no-op, so a button only counts if it appears an odd
+/// number of times. e.g. `[0, 1, 0]` costs the same as `[1]`.
+///
+/// Returns an error if a pressed button doesn't exist or has no joltage.
+fn joltage_of(machine: &Machine, buttons: &[usize]) -> Result<u64> {
+    buttons
+        .iter()
+        .counts()
+        .into_iter()
+        .filter(|&(_, count)| count % 2 == 1)
+        .map(|(&idx, _)| {
+            anyhow::ensure!(
+                idx < machine.buttons.len(),
+                "Button {idx} doesn't exist, the machine has {} buttons",
+                machine.buttons.len()
+            );
+            machine
+                .joltages
+                .get(idx)
+                .map(|&joltage| joltage as u64)
+                .ok_or_else(|| anyhow::anyhow!("Button {idx} has no joltage"))
+        })
+        .sum()
+}
+
 /// Part One - Find minimum number of button presses to reach the machine target state.
 ///
 /// It performs [Breadth First Search](https://en.wikipedia.org/wiki/Breadth-first_search) on a graph
 /// where each node is all possible states of the light bulbs, and each edge is a possible state transition
-/// after pressing any button. It starts at state `0` (all bulbs are off).
+/// after pressing any button. It starts with all bulbs off.
 ///
 /// Pressing a button is presented with XOR, since a button toggles the bulb.
+#[allow(dead_code)]
 fn min_presses_to_target_state(machine: &Machine) -> usize {
-    let mut explored_states: HashSet<u16> = HashSet::new();
-    let mut queue: VecDeque<(usize, u16)> = VecDeque::from([(0, 0)]); // (round_num, state_to_explore)
+    min_presses_bfs(machine).unwrap_or_else(|| {
+        panic!(
+            "Machine target state [{}] is unreachable",
+            debug_machine_state(machine.target_state, machine.bulb_count)
+        )
+    })
+}
+
+/// Machines with more bulbs than this are solved with [min_presses_gf2] instead of [min_presses_bfs],
+/// since BFS explores up to `2^bulb_count` states.
+const BFS_MAX_BULB_COUNT: usize = 10;
+
+/// Max number of buttons for [gf2_eliminate], since pressed buttons are tracked as bits of a `u64`.
+const GF2_MAX_BUTTONS: usize = u64::BITS as usize;
+
+/// Max size of the null space for [min_press_set_gf2], since it tries all `2^len` combinations.
+const GF2_MAX_NULL_SPACE: usize = 20;
+
+/// Solves a single machine, picking the faster algorithm based on the machine's state-space size.
+/// Falls back to [min_presses_bfs] if the machine is too large for [min_presses_gf2].
+///
+/// Returns `None` if the target state is unreachable.
+fn solve_machine(machine: &Machine, part: Part) -> Option<usize> {
+    match part {
+        Part::One if machine.bulb_count > BFS_MAX_BULB_COUNT => {
+            min_presses_gf2(machine).or_else(|| min_presses_bfs(machine))
+        }
+        Part::One => min_presses_bfs(machine),
+        Part::Two => min_joltage_to_target_state(machine),
+    }
+}
+
+/// Part Two - Find minimum total joltage of button presses to reach the machine target state, where
+/// pressing button `i` costs `joltages[i]` (see [joltage_of]). Buttons without a joltage can't be
+/// pressed.
+///
+/// Same graph as [min_presses_to_target_state], but the edges are weighted by the button cost, so
+/// it performs [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm)
+/// instead of BFS.
+///
+/// Returns `None` if the target state is unreachable.
+fn min_joltage_to_target_state(machine: &Machine) -> Option<usize> {
+    let button_costs: Vec<(BulbState, usize)> = machine
+        .buttons
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, &button)| {
+            let cost = joltage_of(machine, &[idx]).ok()?;
+            Some((button, cost as usize))
+        })
+        .collect();
+
+    let initial_state = BulbState::default();
+    let mut min_costs: HashMap<BulbState, usize> = HashMap::from([(initial_state, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, initial_state))]); // (cost, state)
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if state == machine.target_state {
+            return Some(cost);
+        }
+        if min_costs
+            .get(&state)
+            .is_some_and(|&min_cost| cost > min_cost)
+        {
+            continue; // Already reached this state with a lower cost
+        }
+        for &(button, button_cost) in &button_costs {
+            let next_state = state ^ button;
+            let next_cost = cost + button_cost;
+            if min_costs
+                .get(&next_state)
+                .is_none_or(|&min_cost| next_cost < min_cost)
+            {
+                min_costs.insert(next_state, next_cost);
+                queue.push(Reverse((next_cost, next_state)));
+            }
+        }
+    }
+    None
+}
+
+/// Same as [min_presses_to_target_state], but returns `None` if the target state is unreachable.
+fn min_presses_bfs(machine: &Machine) -> Option<usize> {
+    let mut min_presses = None;
+    bfs_states(machine, usize::MAX, |state, round_num| {
+        if state == machine.target_state {
+            min_presses = Some(round_num);
+        }
+        min_presses.is_some()
+    });
+    min_presses
+}
+
+/// Counts the number of distinct states that can be reached within `max_presses` button presses,
+/// including the initial state where all bulbs are off.
+#[allow(dead_code)]
+fn states_within(machine: &Machine, max_presses: usize) -> usize {
+    let mut count = 0;
+    bfs_states(machine, max_presses, |_, _| {
+        count += 1;
+        false
+    });
+    count
+}
+
+/// Visits every distinct state reachable within `max_presses` button presses in BFS order, starting
+/// with all bulbs off. `visit` is called with `(state, round_num)` and can return `true` to stop early.
+fn bfs_states<F: FnMut(BulbState, usize) -> bool>(
+    machine: &Machine,
+    max_presses: usize,
+    mut visit: F,
+) {
+    let initial_state = BulbState::default();
+    let mut explored_states: HashSet<BulbState> = HashSet::from([initial_state]);
+    let mut queue: VecDeque<(usize, BulbState)> = VecDeque::from([(0, initial_state)]); // (round_num, state_to_explore)
 
-    while let Some((round_num, state)) = &queue.pop_front() {
-        if *state == machine.target_state {
-            return *round_num;
+    while let Some((round_num, state)) = queue.pop_front() {
+        if visit(state, round_num) {
+            return;
+        }
+        if round_num == max_presses {
+            continue;
         }
-        explored_states.insert(*state);
         machine.buttons.iter().for_each(|&button| {
-            let next_state = *state ^ button;
-            if !explored_states.contains(&next_state) {
+            let next_state = state ^ button;
+            if explored_states.insert(next_state) {
                 queue.push_back((round_num + 1, next_state));
             }
         });
     }
+}
+
+/// Alternative of [min_presses_bfs] that solves the buttons as a system of linear equations over
+/// [GF(2)](https://en.wikipedia.org/wiki/GF(2)), which scales to machines with many bulbs.
+///
+/// Pressing a button twice cancels itself out, so each button is either pressed once or not at all.
+/// Gaussian elimination finds one set of buttons that reaches the target state, plus a basis of
+/// button sets that toggle nothing (the null space). Every solution is the first set XOR-ed with
+/// some combination of the null space, so we try all combinations and pick the fewest presses.
+///
+/// Returns `None` if the target state is unreachable.
+fn min_presses_gf2(machine: &Machine) -> Option<usize> {
+    min_press_set_gf2(machine).map(|pressed| pressed.count_ones() as usize)
+}
+
+/// Finds a set of buttons with the fewest presses that reaches the target state, as a bitmask of
+/// button indexes. See [min_presses_gf2] for the algorithm.
+///
+/// Returns `None` if the target state is unreachable.
+///
+/// Also returns `None` if the machine is too large to solve this way, i.e. it has more than
+/// [GF2_MAX_BUTTONS] buttons or the null space has more than [GF2_MAX_NULL_SPACE] entries.
+fn min_press_set_gf2(machine: &Machine) -> Option<u64> {
+    if machine.buttons.len() > GF2_MAX_BUTTONS {
+        return None;
+    }
+    let (basis, null_space) = gf2_eliminate(&machine.buttons);
+
+    let (residual, solution) = gf2_reduce(&basis, machine.target_state, 0);
+    if !residual.is_empty() || null_space.len() > GF2_MAX_NULL_SPACE {
+        return None;
+    }
+
+    (0..1_u64 << null_space.len())
+        .map(|subset| {
+            null_space
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (subset >> i) & 1 == 1)
+                .fold(solution, |acc, (_, pressed)| acc ^ pressed)
+        })
+        .min_by_key(|pressed| pressed.count_ones())
+}
+
+/// Finds whether each button is pressed an odd number of times in a solution with the fewest
+/// presses. Pressing a button twice cancels itself out, so XOR-ing the buttons marked `true` gives
+/// the target state.
+///
+/// If there are several solutions with the fewest presses, any one of them is returned. Returns
+/// `None` if the target state is unreachable.
+#[allow(dead_code)]
+fn press_parity(machine: &Machine) -> Option<Vec<bool>> {
+    let pressed = min_press_set_gf2(machine)?;
+    let parity = (0..machine.buttons.len())
+        .map(|idx| (pressed >> idx) & 1 == 1)
+        .collect();
+    Some(parity)
+}
+
+/// Performs Gaussian elimination over GF(2) on the `buttons`. Returns `(basis, null_space)`:
+///
+/// - `basis` - Each entry is `(state, pressed_buttons)`, where `pressed_buttons` is a bitmask of
+///   button indexes whose XOR gives `state`. Sorted in descending order so each has a distinct
+///   leading bit.
+/// - `null_space` - Bitmasks of button indexes whose XOR gives `0` (i.e. toggles nothing).
+///
+/// # Panic
+///
+/// Panics if there are more than [GF2_MAX_BUTTONS] buttons.
+fn gf2_eliminate(buttons: &[BulbState]) -> (Vec<(BulbState, u64)>, Vec<u64>) {
+    assert!(
+        buttons.len() <= GF2_MAX_BUTTONS,
+        "{} buttons can't be tracked in a bitmask, max is {GF2_MAX_BUTTONS}",
+        buttons.len()
+    );
+    let mut basis: Vec<(BulbState, u64)> = vec![];
+    let mut null_space: Vec<u64> = vec![];
+
+    for (idx, &button) in buttons.iter().enumerate() {
+        match gf2_reduce(&basis, button, 1 << idx) {
+            (state, pressed) if state.is_empty() => null_space.push(pressed),
+            entry => {
+                basis.push(entry);
+                basis.sort_by_key(|&(state, _)| Reverse(state));
+            }
+        }
+    }
+    (basis, null_space)
+}
 
-    panic!(
-        "Machine target state [{}] is unreachable",
-        debug_machine_state(machine.target_state, machine.bulb_count)
+/// Reduces `state` with the `basis` from [gf2_eliminate], while tracking the `pressed` buttons.
+/// The returned state is empty if `state` can be made from the basis.
+fn gf2_reduce(basis: &[(BulbState, u64)], state: BulbState, pressed: u64) -> (BulbState, u64) {
+    basis.iter().fold(
+        (state, pressed),
+        |(state, pressed), &(b_state, b_pressed)| {
+            // XOR-ing is only smaller if `state` has the basis' leading bit set
+            if state ^ b_state < state {
+                (state ^ b_state, pressed ^ b_pressed)
+            } else {
+                (state, pressed)
+            }
+        },
     )
 }
 
-/// e.g. `debug_machine_state(6, 4)` = `"##.."` (6 = `0011` binary)
-fn debug_machine_state(current_state: u16, bulb_count: usize) -> String {
-    // `width$` = named parameter
-    // `:05` = left pad a number with `0` till reach total length `5`
-    // `:0width$` = left pad a number with `0` till reach total length of `width`
-    // `b` = format as binary
-    let s = format!("{current_state:0width$b}", width = bulb_count);
-    let s: String = s.chars().rev().collect();
-    s.replace("0", ".").replace("1", "#").to_string()
+/// Finds the indexes of a maximal subset of buttons that are linearly independent over GF(2), i.e.
+/// no button in the subset can be made by pressing other buttons in the subset. The rest of the
+/// buttons are redundant. Indexes are in ascending order.
+#[allow(dead_code)]
+fn independent_buttons(machine: &Machine) -> Vec<usize> {
+    let (basis, _) = gf2_eliminate(&machine.buttons);
+    // Each basis entry is added while processing a button, and its `pressed_buttons` only has that
+    // button and earlier ones. So the highest bit is the index of the button that was added.
+    basis
+        .iter()
+        .map(|&(_, pressed)| (u64::BITS - 1 - pressed.leading_zeros()) as usize)
+        .sorted()
+        .collect()
+}
+
+/// Splits the machines from the input into `(solvable, unsolvable)`. A machine is solvable if its
+/// target state lies in the GF(2) span of its buttons (see [min_presses_gf2]).
+#[allow(dead_code)]
+fn partition_machines(input: &str) -> (Vec<Machine>, Vec<Machine>) {
+    input.lines().map(Machine::from_input).partition(|machine| {
+        let (basis, _) = gf2_eliminate(&machine.buttons);
+        gf2_reduce(&basis, machine.target_state, 0).0.is_empty()
+    })
+}
+
+/// Exports the result of each machine in the input as CSV, with the columns:
+///
+/// - `bulb_count`
+/// - `min_presses` - Part One answer, or empty if unreachable
+/// - `min_joltage` - Part Two answer, or empty if unreachable
+/// - `reachable` - Whether the target state is reachable (see [partition_machines])
+#[allow(dead_code)]
+fn solutions_to_csv(input: &str) -> Result<String> {
+    let header = "bulb_count,min_presses,min_joltage,reachable";
+    let rows = input.lines().map(|line| {
+        let machine = Machine::from_input(line);
+        let min_presses = solve_machine(&machine, Part::One);
+        let min_joltage = solve_machine(&machine, Part::Two);
+        format!(
+            "{},{},{},{}",
+            machine.bulb_count,
+            min_presses.map_or(String::new(), |presses| presses.to_string()),
+            min_joltage.map_or(String::new(), |joltage| joltage.to_string()),
+            min_presses.is_some()
+        )
+    });
+    Ok(std::iter::once(header.to_string()).chain(rows).join("\n"))
+}
+
+/// e.g. `debug_machine_state(BulbState(6), 4)` = `".##."` (6 = `0110` binary)
+fn debug_machine_state(current_state: BulbState, bulb_count: usize) -> String {
+    // `width$` = named parameter, which is the number of bulbs to render
+    format!("{current_state:width$}", width = bulb_count)
 }
 
 fn main() -> Result<()> {
-    let input = fs::read_to_string("puzzle_inputs/day10.txt")?;
+    let input = read_input("puzzle_inputs/day10.txt")?;
     let input = input.trim();
 
-    let part_1_solution = solve_day10(input, Part::One);
-    // let part_2_solution = solve_day10(input, Part::Two);
+    let part_1_solution = solve_day10(input, Part::One)?;
     println!("Part 1 Solution: {part_1_solution}");
-    // println!("Part 2 Solution: {part_2_solution}");
+    let part_2_solution = solve_day10(input, Part::Two)?;
+    println!("Part 2 Solution: {part_2_solution}");
     Ok(())
 }
 
@@ -133,13 +431,23 @@ mod tests {
     fn test_machine_from_input() {
         assert_eq!(
             Machine::from_input(r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
-            Machine::new(4, 6, vec![8, 10, 4, 12, 5, 3], vec![3, 5, 4, 7])
+            Machine::new(
+                4,
+                BulbState(6),
+                [8, 10, 4, 12, 5, 3].map(BulbState).to_vec(),
+                vec![3, 5, 4, 7]
+            )
         );
         assert_eq!(
             Machine::from_input(
                 r"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"
             ),
-            Machine::new(6, 46, vec![31, 25, 55, 6], vec![10, 11, 11, 5, 10, 5])
+            Machine::new(
+                6,
+                BulbState(46),
+                [31, 25, 55, 6].map(BulbState).to_vec(),
+                vec![10, 11, 11, 5, 10, 5]
+            )
         );
     }
 
@@ -159,11 +467,259 @@ mod tests {
         assert_eq!(min_presses_to_target_state(&machine), 2); // (0,3,4) -> (0,1,2,4,5)
     }
 
+    #[test]
+    fn test_min_joltage_to_target_state() {
+        // Puzzle examples
+        let machine = Machine::from_input(r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        // (1,3) -> (2,3), since (0,2) and (0,1) have no joltage
+        assert_eq!(min_joltage_to_target_state(&machine), Some(5 + 7));
+
+        let machine =
+            Machine::from_input(r"[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}");
+        assert_eq!(min_joltage_to_target_state(&machine), Some(12 + 7 + 2)); // last 3 buttons
+
+        let machine = Machine::from_input(
+            r"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+        );
+        assert_eq!(min_joltage_to_target_state(&machine), Some(11 + 11)); // (0,3,4) -> (0,1,2,4,5)
+
+        // Fewest presses is not always the cheapest
+        let machine = Machine::from_input(r"[###.] (0,1,2,3) (3) (0) (1) (2) {9,1,1,1,1}");
+        assert_eq!(min_presses_bfs(&machine), Some(2)); // (0,1,2,3) -> (3)
+        assert_eq!(joltage_of(&machine, &[0, 1]).unwrap(), 9 + 1);
+        assert_eq!(min_joltage_to_target_state(&machine), Some(3)); // (0) -> (1) -> (2)
+
+        // Bulb 2 can't be toggled by any button
+        let machine = Machine::from_input(r"[..#] (0) (0,1) {1,1}");
+        assert_eq!(min_joltage_to_target_state(&machine), None);
+        assert_eq!(solve_machine(&machine, Part::Two), None);
+    }
+
+    #[test]
+    fn test_press_parity() {
+        let apply = |machine: &Machine, parity: &[bool]| {
+            machine
+                .buttons
+                .iter()
+                .zip(parity)
+                .filter(|&(_, &pressed)| pressed)
+                .fold(BulbState::default(), |state, (&button, _)| state ^ button)
+        };
+
+        // Puzzle examples
+        for (input, presses) in [
+            (r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}", 2),
+            (
+                r"[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+                3,
+            ),
+            (
+                r"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+                2,
+            ),
+        ] {
+            let machine = Machine::from_input(input);
+            let parity = press_parity(&machine).unwrap();
+            assert_eq!(parity.len(), machine.buttons.len());
+            assert_eq!(parity.iter().filter(|&&pressed| pressed).count(), presses);
+            assert_eq!(apply(&machine, &parity), machine.target_state);
+        }
+
+        // Only solution is pressing the last 2 buttons
+        let machine = Machine::from_input(r"[##.] (0,1,2) (0) (1) {1,1,1}");
+        assert_eq!(press_parity(&machine), Some(vec![false, true, true]));
+
+        // Already at target state
+        let machine = Machine::from_input(r"[...] (0) (1) {1,1,1}");
+        assert_eq!(press_parity(&machine), Some(vec![false, false]));
+
+        // Bulb 2 can't be toggled by any button
+        let machine = Machine::from_input(r"[..#] (0) (0,1) {1,1}");
+        assert_eq!(press_parity(&machine), None);
+    }
+
+    #[test]
+    fn test_joltage_of() {
+        let machine = Machine::from_input(r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        assert_eq!(joltage_of(&machine, &[]).unwrap(), 0);
+        assert_eq!(joltage_of(&machine, &[1]).unwrap(), 5);
+        assert_eq!(joltage_of(&machine, &[0, 3]).unwrap(), 3 + 7);
+
+        // Pressing twice cancels out
+        assert_eq!(joltage_of(&machine, &[0, 3, 0]).unwrap(), 7);
+        assert_eq!(joltage_of(&machine, &[2, 2]).unwrap(), 0);
+        assert_eq!(joltage_of(&machine, &[2, 2, 2]).unwrap(), 4);
+
+        // Buttons 4 and 5 exist but have no joltage
+        assert!(joltage_of(&machine, &[4]).is_err());
+        assert!(joltage_of(&machine, &[1, 5]).is_err());
+        // Button doesn't exist
+        assert!(joltage_of(&machine, &[6]).is_err());
+        // Cancelled out, so it's never looked up
+        assert_eq!(joltage_of(&machine, &[4, 4]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_solve_machine() {
+        let inputs = [
+            r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}",
+            r"[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+            r"[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+        ];
+        for (input, expected) in inputs.iter().zip([2, 3, 2]) {
+            let machine = Machine::from_input(input);
+            assert_eq!(min_presses_bfs(&machine), Some(expected));
+            assert_eq!(min_presses_gf2(&machine), Some(expected));
+            assert_eq!(solve_machine(&machine, Part::One), Some(expected));
+        }
+
+        // Bulb 2 can't be toggled by any button
+        let machine = Machine::from_input(r"[..#] (0) (0,1) {1,1}");
+        assert_eq!(min_presses_bfs(&machine), None);
+        assert_eq!(min_presses_gf2(&machine), None);
+        assert_eq!(solve_machine(&machine, Part::One), None);
+
+        // Uses GF(2) since it has more bulbs than `BFS_MAX_BULB_COUNT`
+        let machine = Machine::from_input(
+            r"[##########.#] (0,1,2,3,4) (5,6,7,8,9) (10) (11) (10,11) {1,1,1,1,1}",
+        );
+        assert_eq!(solve_machine(&machine, Part::One), Some(3));
+        assert_eq!(min_presses_bfs(&machine), Some(3));
+
+        // More than 16 bulbs
+        let machine = Machine::from_input(r"[...................#] (0,19) (0) (19) {1,1}");
+        assert_eq!(machine.bulb_count, 20);
+        assert_eq!(solve_machine(&machine, Part::One), Some(1));
+
+        // More buttons than `GF2_MAX_BUTTONS` falls back to BFS
+        let buttons = (0..=GF2_MAX_BUTTONS)
+            .map(|idx| format!("({})", idx % 11))
+            .join(" ");
+        let machine = Machine::from_input(&format!("[..........#] {buttons} {{1}}"));
+        assert_eq!(machine.buttons.len(), GF2_MAX_BUTTONS + 1);
+        assert_eq!(min_presses_gf2(&machine), None);
+        assert_eq!(solve_machine(&machine, Part::One), Some(1));
+
+        // 11 bulbs and 40 buttons gives a null space of 29, so it falls back to BFS
+        let buttons = (0..40).map(|idx| format!("({})", idx % 11)).join(" ");
+        let machine = Machine::from_input(&format!("[#.........#] {buttons} {{1}}"));
+        assert_eq!(min_presses_gf2(&machine), None);
+        assert_eq!(solve_machine(&machine, Part::One), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be tracked in a bitmask")]
+    fn test_gf2_eliminate_too_many_buttons() {
+        gf2_eliminate(&vec![BulbState(1); GF2_MAX_BUTTONS + 1]);
+    }
+
+    #[test]
+    fn test_min_press_set_gf2_null_space_too_large() {
+        // Every button after the first is redundant
+        let buttons = "(0) ".repeat(GF2_MAX_NULL_SPACE + 1);
+        let machine = Machine::from_input(&format!("[#] {buttons}{{1}}"));
+        assert_eq!(min_press_set_gf2(&machine), Some(1));
+
+        let buttons = "(0) ".repeat(GF2_MAX_NULL_SPACE + 2);
+        let machine = Machine::from_input(&format!("[#] {buttons}{{1}}"));
+        assert_eq!(min_press_set_gf2(&machine), None);
+        assert_eq!(solve_machine(&machine, Part::One), Some(1));
+    }
+
+    #[test]
+    fn test_partition_machines() {
+        let input = r"
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[..#] (0) (0,1) {1,1}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[#..] (0,1) (1,2) {1,1,1}
+"
+        .trim();
+        let machines: Vec<Machine> = input.lines().map(Machine::from_input).collect();
+
+        let (solvable, unsolvable) = partition_machines(input);
+        assert_eq!(solvable, vec![machines[0].clone(), machines[2].clone()]);
+        assert_eq!(unsolvable, vec![machines[1].clone(), machines[3].clone()]);
+
+        assert_eq!(partition_machines(""), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_independent_buttons() {
+        // (0,1,2) = (0,1) XOR (2)
+        let machine = Machine::from_input(r"[.##.] (0,1) (2) (0,1,2) (3) {1,1,1,1}");
+        assert_eq!(independent_buttons(&machine), vec![0, 1, 3]);
+
+        // (3) is a duplicate of an earlier button
+        let machine = Machine::from_input(r"[.##.] (3) (1,3) (3) (0,2) {1,1,1,1}");
+        assert_eq!(independent_buttons(&machine), vec![0, 1, 3]);
+
+        // Puzzle example: 6 buttons, but at most 4 can be independent for 4 bulbs
+        let machine = Machine::from_input(r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        let independent = independent_buttons(&machine);
+        assert_eq!(independent, vec![0, 1, 2, 4]);
+        let machine = Machine::new(
+            machine.bulb_count,
+            machine.target_state,
+            independent
+                .iter()
+                .map(|&idx| machine.buttons[idx])
+                .collect(),
+            machine.joltages.clone(),
+        );
+        assert_eq!(states_within(&machine, machine.buttons.len()), 16);
+    }
+
+    #[test]
+    fn test_states_within() {
+        let machine = Machine::from_input(r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        assert_eq!(states_within(&machine, 0), 1);
+        assert_eq!(states_within(&machine, 1), 7); // 0 + each of the 6 distinct buttons
+        assert_eq!(states_within(&machine, 2), 16); // Every 4-bulb state
+        assert_eq!(states_within(&machine, 10), 16);
+
+        // Only 2 buttons, so at most 4 states can be reached
+        let machine = Machine::from_input(r"[..#] (0) (0,1) {1,1}");
+        assert_eq!(states_within(&machine, 1), 3);
+        assert_eq!(states_within(&machine, 2), 4);
+    }
+
+    #[test]
+    fn test_solutions_to_csv() {
+        let input = r"
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[..#] (0) (0,1) {1,1}
+"
+        .trim();
+        let csv = solutions_to_csv(input).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            vec![
+                "bulb_count,min_presses,min_joltage,reachable",
+                "4,2,12,true",
+                "5,3,21,true",
+                "3,,,false",
+            ]
+        );
+
+        assert_eq!(
+            solutions_to_csv("").unwrap(),
+            "bulb_count,min_presses,min_joltage,reachable"
+        );
+    }
+
     #[test]
     fn test_debug_machine_state() {
-        assert_eq!(debug_machine_state(7, 4), String::from("###."));
-        assert_eq!(debug_machine_state(14, 7), String::from(".###..."));
-        assert_eq!(debug_machine_state(22, 6), String::from(".##.#."));
+        assert_eq!(debug_machine_state(BulbState(7), 4), String::from("###."));
+        assert_eq!(
+            debug_machine_state(BulbState(14), 7),
+            String::from(".###...")
+        );
+        assert_eq!(
+            debug_machine_state(BulbState(22), 6),
+            String::from(".##.#.")
+        );
     }
 
     #[test]
@@ -175,6 +731,11 @@ mod tests {
 "
         .trim();
 
-        assert_eq!(solve_day10(input, Part::One), 7);
+        assert_eq!(solve_day10(input, Part::One).unwrap(), 7);
+        assert_eq!(solve_day10(input, Part::Two).unwrap(), 12 + 21 + 22);
+
+        // Bulb 2 can't be toggled by any button
+        assert!(solve_day10(r"[..#] (0) (0,1) {1,1}", Part::One).is_err());
+        assert!(solve_day10(r"[..#] (0) (0,1) {1,1}", Part::Two).is_err());
     }
 }
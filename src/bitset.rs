@@ -0,0 +1,190 @@
+//! Small fixed-size bitset, e.g. for representing the on/off states of a row of light bulbs.
+
+use std::{
+    fmt::Display,
+    ops::{BitXor, BitXorAssign},
+};
+
+/// On/off states of up to [Self::CAPACITY] bulbs, stored as bits of a `u64`. The bulb at index `i`
+/// is bit `i`, so the leftmost bulb is the least significant bit.
+///
+/// e.g. `[.##.]` = `0110` in binary (read right-to-left), or `6` in decimal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BulbState(pub u64);
+
+impl BulbState {
+    /// Maximum number of bulbs that can be stored.
+    pub const CAPACITY: usize = u64::BITS as usize;
+
+    /// Parses a pattern of `#` (on) and `.` (off) bulbs, e.g. `".##."`.
+    ///
+    /// Returns `None` if the pattern has other characters or more than [Self::CAPACITY] bulbs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use advent_of_code_2025::bitset::BulbState;
+    /// assert_eq!(BulbState::from_pattern(".##."), Some(BulbState(6)));
+    /// assert_eq!(BulbState::from_pattern(".x"), None);
+    /// ```
+    pub fn from_pattern(pattern: &str) -> Option<Self> {
+        if pattern.chars().count() > Self::CAPACITY {
+            return None;
+        }
+        pattern
+            .chars()
+            .enumerate()
+            .try_fold(Self::default(), |mut state, (idx, c)| {
+                match c {
+                    '#' => state.set(idx, true),
+                    '.' => {}
+                    _ => return None,
+                }
+                Some(state)
+            })
+    }
+
+    /// Gets whether the bulb at `idx` is on.
+    pub fn get(&self, idx: usize) -> bool {
+        (self.0 >> idx) & 1 == 1
+    }
+
+    /// Turns the bulb at `idx` on or off.
+    pub fn set(&mut self, idx: usize, on: bool) {
+        if on {
+            self.0 |= 1 << idx;
+        } else {
+            self.0 &= !(1 << idx);
+        }
+    }
+
+    /// Flips the bulb at `idx`.
+    pub fn toggle(&mut self, idx: usize) {
+        self.0 ^= 1 << idx;
+    }
+
+    /// Number of bulbs that are on.
+    pub fn count_on(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Whether all bulbs are off.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Combines the toggles of 2 states, e.g. applying a button to the current state.
+impl BitXor for BulbState {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for BulbState {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+/// Renders the bulbs from left to right as `#` (on) and `.` (off).
+///
+/// The width is the number of bulbs to render. Without a width, it renders up to the last bulb
+/// that is on.
+///
+/// ```
+/// # use advent_of_code_2025::bitset::BulbState;
+/// assert_eq!(format!("{:4}", BulbState(6)), ".##.");
+/// assert_eq!(BulbState(6).to_string(), ".##");
+/// ```
+impl Display for BulbState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = f
+            .width()
+            .unwrap_or((u64::BITS - self.0.leading_zeros()) as usize);
+        for idx in 0..width {
+            let c = if idx < Self::CAPACITY && self.get(idx) {
+                '#'
+            } else {
+                '.'
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_set_toggle_get() {
+        let mut state = BulbState::default();
+        assert!(state.is_empty());
+
+        state.set(0, true);
+        state.set(3, true);
+        assert_eq!(state, BulbState(0b1001));
+        assert!(state.get(0));
+        assert!(!state.get(1));
+        assert!(state.get(3));
+
+        state.set(3, false);
+        state.set(0, true);
+        assert_eq!(state, BulbState(0b1));
+
+        state.toggle(0);
+        state.toggle(63);
+        assert_eq!(state, BulbState(1 << 63));
+        assert!(state.get(63));
+        assert_eq!(state.count_on(), 1);
+
+        state.toggle(63);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_xor() {
+        let mut state = BulbState(0b0110);
+        assert_eq!(state ^ BulbState(0b0011), BulbState(0b0101));
+        state ^= BulbState(0b0110);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_from_pattern() {
+        assert_eq!(BulbState::from_pattern(".##."), Some(BulbState(6)));
+        assert_eq!(BulbState::from_pattern("...#."), Some(BulbState(8)));
+        assert_eq!(BulbState::from_pattern(".###.#"), Some(BulbState(46)));
+        assert_eq!(BulbState::from_pattern(""), Some(BulbState(0)));
+
+        // More than 16 bulbs
+        let pattern = format!("{}#", ".".repeat(40));
+        assert_eq!(BulbState::from_pattern(&pattern), Some(BulbState(1 << 40)));
+        let pattern = "#".repeat(64);
+        assert_eq!(BulbState::from_pattern(&pattern), Some(BulbState(u64::MAX)));
+
+        assert_eq!(BulbState::from_pattern(&"#".repeat(65)), None);
+        assert_eq!(BulbState::from_pattern("#.x"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{:4}", BulbState(7)), "###.");
+        assert_eq!(format!("{:7}", BulbState(14)), ".###...");
+        assert_eq!(format!("{:6}", BulbState(22)), ".##.#.");
+        assert_eq!(format!("{:3}", BulbState(0)), "...");
+
+        assert_eq!(BulbState(22).to_string(), ".##.#");
+        assert_eq!(BulbState(0).to_string(), "");
+
+        for pattern in [".##.", "...#.", ".###.#", "#"] {
+            let state = BulbState::from_pattern(pattern).unwrap();
+            assert_eq!(format!("{state:width$}", width = pattern.len()), pattern);
+        }
+    }
+}
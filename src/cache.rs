@@ -0,0 +1,100 @@
+//! Caches puzzle answers on disk, so re-running a day with unchanged input skips the computation.
+//!
+//! Caching is only enabled with the `cache` feature. Otherwise, [cached_solve] always computes.
+
+use std::{fmt::Display, str::FromStr};
+
+/// Directory where answers are cached, relative to the working directory.
+pub const CACHE_DIR: &str = ".aoc_cache";
+
+/// Returns the cached answer for `key` if present, otherwise computes it with `solve` and caches it.
+///
+/// `key` should identify both the puzzle part and its input, since the cache entry is looked up by
+/// its hash (e.g. `format!("day01-{part:?}-{input}")`). Any I/O error is ignored and falls back to
+/// computing the answer.
+pub fn cached_solve<F: Fn() -> T, T: Display + FromStr>(key: &str, solve: F) -> T {
+    #[cfg(feature = "cache")]
+    {
+        cached_solve_in(std::path::Path::new(CACHE_DIR), key, solve)
+    }
+    #[cfg(not(feature = "cache"))]
+    {
+        let _ = key;
+        solve()
+    }
+}
+
+/// Same as [cached_solve], but stores the answers in `dir`.
+#[cfg(feature = "cache")]
+fn cached_solve_in<F: Fn() -> T, T: Display + FromStr>(
+    dir: &std::path::Path,
+    key: &str,
+    solve: F,
+) -> T {
+    use std::{
+        fs,
+        hash::{DefaultHasher, Hash, Hasher},
+    };
+
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let path = dir.join(format!("{:016x}.txt", hasher.finish()));
+
+    if let Some(answer) = fs::read_to_string(&path)
+        .ok()
+        .and_then(|cached| cached.parse().ok())
+    {
+        return answer;
+    }
+
+    let answer = solve();
+    let _ = fs::create_dir_all(dir).and_then(|_| fs::write(&path, answer.to_string()));
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_cached_solve_in() {
+        use std::cell::Cell;
+
+        let dir = std::env::temp_dir().join(format!("aoc_cache_test_{}", std::process::id()));
+        let call_count = Cell::new(0);
+        let solve = || {
+            call_count.set(call_count.get() + 1);
+            42_u64
+        };
+
+        assert_eq!(cached_solve_in(&dir, "day01-One-L68", solve), 42);
+        assert_eq!(call_count.get(), 1);
+
+        // Same key is read from cache
+        assert_eq!(cached_solve_in(&dir, "day01-One-L68", solve), 42);
+        assert_eq!(call_count.get(), 1);
+
+        // Different key is recomputed
+        assert_eq!(cached_solve_in(&dir, "day01-One-L69", solve), 42);
+        assert_eq!(call_count.get(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(not(feature = "cache"))]
+    #[test]
+    fn test_cached_solve_without_feature() {
+        use std::cell::Cell;
+
+        let call_count = Cell::new(0);
+        let solve = || {
+            call_count.set(call_count.get() + 1);
+            42_u64
+        };
+        assert_eq!(cached_solve("day01-One-L68", solve), 42);
+        assert_eq!(cached_solve("day01-One-L68", solve), 42);
+        assert_eq!(call_count.get(), 2);
+    }
+}
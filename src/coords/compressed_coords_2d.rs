@@ -40,14 +40,46 @@ impl CompressedCoords2D {
     /// Compresses the input list of `coords`.
     #[must_use]
     pub fn from_coords(coords: &[Coords2D]) -> Self {
+        Self::compress(coords, false)
+    }
+
+    /// Same as [Self::from_coords], but leaves a gap of one compressed value between two original
+    /// values that are not consecutive integers. The gap stands for all the original values in
+    /// between, so shapes drawn on the compressed grid keep their empty space (e.g. a 1-wide notch
+    /// between `x = 2` and `x = 9` is not squashed). Gaps have no original value in
+    /// [Self::to_original].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use advent_of_code_2025::coords::{Coords2D, CompressedCoords2D};
+    /// let coords = vec![Coords2D::new(2, 0), Coords2D::new(3, 0), Coords2D::new(9, 0)];
+    ///
+    /// let compressed = CompressedCoords2D::from_coords_with_gaps(&coords);
+    /// // `4..=8` are squashed into the gap at `x = 2`
+    /// assert_eq!(compressed.coords[2], Coords2D::new(3, 0));
+    /// assert_eq!(compressed.to_original(&Coords2D::new(2, 0)), None);
+    /// ```
+    #[must_use]
+    pub fn from_coords_with_gaps(coords: &[Coords2D]) -> Self {
+        Self::compress(coords, true)
+    }
+
+    fn compress(coords: &[Coords2D], with_gaps: bool) -> Self {
         let compress = |extract: fn(&Coords2D) -> i64| -> BiMap<i64, i64> {
+            let mut new = -1;
+            let mut prev_old: Option<i64> = None;
             coords
                 .iter()
                 .map(extract)
                 .sorted()
                 .dedup()
-                .enumerate()
-                .map(|(new, old)| (old, new as i64))
+                .map(|old| {
+                    let has_gap = prev_old.is_some_and(|prev_old| old - prev_old > 1);
+                    new += if with_gaps && has_gap { 2 } else { 1 };
+                    prev_old = Some(old);
+                    (old, new)
+                })
                 .collect()
         };
         let x_old_to_new_map = compress(|c| c.x);
@@ -73,13 +105,21 @@ impl CompressedCoords2D {
     /// Gets the largest compressed x coordinate's value.
     #[inline]
     pub fn max_x(&self) -> i64 {
-        self.x_old_to_new_map.len().saturating_sub(1) as i64
+        self.x_old_to_new_map
+            .right_values()
+            .max()
+            .copied()
+            .unwrap_or_default()
     }
 
     /// Gets the largest compressed y coordinate's value.
     #[inline]
     pub fn max_y(&self) -> i64 {
-        self.y_old_to_new_map.len().saturating_sub(1) as i64
+        self.y_old_to_new_map
+            .right_values()
+            .max()
+            .copied()
+            .unwrap_or_default()
     }
 
     /// Decompresses a coordinate back to the original value.
@@ -91,6 +131,42 @@ impl CompressedCoords2D {
             _ => None,
         }
     }
+
+    /// Compresses an original coordinate, or `None` if its x or y value is not in the original list
+    /// of coordinates. This is the reverse of [Self::to_original].
+    pub fn to_compressed(&self, coords: &Coords2D) -> Option<Coords2D> {
+        let x = self.x_old_to_new_map.get_by_left(&coords.x)?;
+        let y = self.y_old_to_new_map.get_by_left(&coords.y)?;
+        Some(Coords2D::new(*x, *y))
+    }
+
+    /// Same as [Self::to_compressed], but an x or y value not in the original list of coordinates is
+    /// snapped to the compressed value of the nearest original value. If the value is right in the
+    /// middle of two original values, it snaps to the smaller one.
+    ///
+    /// Gaps from [Self::from_coords_with_gaps] are never returned. Returns `None` only if there are
+    /// no coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use advent_of_code_2025::coords::{Coords2D, CompressedCoords2D};
+    /// let coords = vec![Coords2D::new(0, 0), Coords2D::new(10, 10)];
+    /// let compressed = CompressedCoords2D::from_coords(&coords);
+    ///
+    /// assert_eq!(compressed.to_compressed_nearest(&Coords2D::new(4, 6)), Some(Coords2D::new(0, 1)));
+    /// assert_eq!(compressed.to_compressed_nearest(&Coords2D::new(5, 99)), Some(Coords2D::new(0, 1)));
+    /// ```
+    pub fn to_compressed_nearest(&self, coords: &Coords2D) -> Option<Coords2D> {
+        let nearest = |map: &BiMap<i64, i64>, value: i64| {
+            map.iter()
+                .min_by_key(|&(&old, _)| (old.abs_diff(value), old))
+                .map(|(_, &new)| new)
+        };
+        let x = nearest(&self.x_old_to_new_map, coords.x)?;
+        let y = nearest(&self.y_old_to_new_map, coords.y)?;
+        Some(Coords2D::new(x, y))
+    }
 }
 
 #[cfg(test)]
@@ -138,6 +214,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_coords_with_gaps() {
+        let input = [
+            Coords2D::new(100, 100),
+            Coords2D::new(100, 500),
+            Coords2D::new(101, 500),
+            Coords2D::new(101, 501),
+            Coords2D::new(800, 501),
+            Coords2D::new(800, 100),
+        ];
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&input);
+        // x: 100 -> 0, 101 -> 1, (gap) -> 2, 800 -> 3
+        // y: 100 -> 0, (gap) -> 1, 500 -> 2, 501 -> 3
+        assert_eq!(
+            &compressed_coords.coords,
+            &vec![
+                Coords2D::new(0, 0),
+                Coords2D::new(0, 2),
+                Coords2D::new(1, 2),
+                Coords2D::new(1, 3),
+                Coords2D::new(3, 3),
+                Coords2D::new(3, 0)
+            ]
+        );
+        assert_eq!(compressed_coords.max_x(), 3);
+        assert_eq!(compressed_coords.max_y(), 3);
+
+        assert_eq!(
+            compressed_coords.to_original(&Coords2D::new(3, 2)),
+            Some(Coords2D::new(800, 500))
+        );
+        assert_eq!(compressed_coords.to_original(&Coords2D::new(2, 2)), None);
+        assert_eq!(compressed_coords.to_original(&Coords2D::new(1, 1)), None);
+
+        let compressed_coords = CompressedCoords2D::from_coords_with_gaps(&[]);
+        assert_eq!(compressed_coords.coords, vec![]);
+        assert_eq!(compressed_coords.max_x(), 0);
+    }
+
     #[test]
     fn test_max_x_and_y() {
         let input = [
@@ -175,4 +290,78 @@ mod tests {
         );
         assert_eq!(compressed_coords.to_original(&Coords2D::new(5, 10)), None);
     }
+
+    #[test]
+    fn test_to_compressed() {
+        let input = [
+            Coords2D::new(100, 100), // (0, 0)
+            Coords2D::new(100, 500), // (0, 2)
+            Coords2D::new(500, 500), // (1, 2)
+            Coords2D::new(500, 300), // (1, 1)
+            Coords2D::new(800, 300), // (2, 1)
+            Coords2D::new(800, 100), // (2, 0)
+        ];
+        let compressed_coords = CompressedCoords2D::from_coords(&input);
+
+        for (original, compressed) in input.iter().zip(&compressed_coords.coords) {
+            assert_eq!(
+                compressed_coords.to_compressed(original).as_ref(),
+                Some(compressed)
+            );
+        }
+        // Not one of the input coordinates, but both values are known
+        assert_eq!(
+            compressed_coords.to_compressed(&Coords2D::new(800, 500)),
+            Some(Coords2D::new(2, 2))
+        );
+        assert_eq!(
+            compressed_coords.to_compressed(&Coords2D::new(800, 200)),
+            None
+        );
+        assert_eq!(
+            compressed_coords.to_compressed(&Coords2D::new(0, 100)),
+            None
+        );
+
+        let gapped_coords = CompressedCoords2D::from_coords_with_gaps(&input);
+        assert_eq!(
+            gapped_coords.to_compressed(&Coords2D::new(500, 300)),
+            Some(Coords2D::new(2, 2))
+        );
+    }
+
+    #[test]
+    fn test_to_compressed_nearest() {
+        let input = [
+            Coords2D::new(100, 100),
+            Coords2D::new(500, 300),
+            Coords2D::new(800, 500),
+        ];
+        let compressed_coords = CompressedCoords2D::from_coords(&input);
+        let nearest = |x, y| compressed_coords.to_compressed_nearest(&Coords2D::new(x, y));
+
+        // Exact hits
+        assert_eq!(nearest(500, 300), Some(Coords2D::new(1, 1)));
+        assert_eq!(nearest(800, 100), Some(Coords2D::new(2, 0)));
+        // In between
+        assert_eq!(nearest(299, 201), Some(Coords2D::new(0, 1)));
+        assert_eq!(nearest(651, 450), Some(Coords2D::new(2, 2)));
+        // Right in the middle snaps to the smaller value
+        assert_eq!(nearest(300, 200), Some(Coords2D::new(0, 0)));
+        assert_eq!(nearest(650, 400), Some(Coords2D::new(1, 1)));
+        // Outside the range
+        assert_eq!(nearest(-1000, 9999), Some(Coords2D::new(0, 2)));
+
+        // Gaps are skipped
+        let gapped_coords = CompressedCoords2D::from_coords_with_gaps(&input);
+        assert_eq!(
+            gapped_coords.to_compressed_nearest(&Coords2D::new(400, 250)),
+            Some(Coords2D::new(2, 2))
+        );
+
+        assert_eq!(
+            CompressedCoords2D::from_coords(&[]).to_compressed_nearest(&Coords2D::new(1, 1)),
+            None
+        );
+    }
 }
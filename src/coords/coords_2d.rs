@@ -1,7 +1,15 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::coords::parse_error::{ParseCoordsError, parse_comma_separated};
 
 /// Represents a 2D coordinate.
-#[derive(Clone, Default, PartialEq, Eq, Hash)]
+///
+/// Coordinates are ordered by `x`, then `y`.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Coords2D {
     pub x: i64,
     pub y: i64,
@@ -13,6 +21,173 @@ impl Coords2D {
     pub fn new(x: i64, y: i64) -> Self {
         Self { x, y }
     }
+
+    /// Computes the [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance)
+    /// with another coordinate.
+    pub fn distance(&self, other: &Self) -> f64 {
+        let dist = (self.x - other.x).pow(2) + (self.y - other.y).pow(2);
+        (dist as f64).sqrt()
+    }
+
+    /// Computes the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) with
+    /// another coordinate, i.e. the number of steps when moving in 4 directions.
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Computes the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) with
+    /// another coordinate, i.e. the number of steps when moving in 8 directions.
+    pub fn chebyshev_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Returns the 4 orthogonally adjacent coordinates in clockwise order, starting from the one
+    /// above (assuming y increases downward): up, right, down, left.
+    ///
+    /// Bounds are not checked, so the coordinates may be negative.
+    pub fn neighbors_4(&self) -> [Coords2D; 4] {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)].map(|(dx, dy)| Coords2D::new(self.x + dx, self.y + dy))
+    }
+
+    /// Returns the 8 surrounding coordinates in clockwise order, starting from the one above
+    /// (assuming y increases downward): up, up-right, right, down-right, down, down-left, left,
+    /// up-left.
+    ///
+    /// Bounds are not checked, so the coordinates may be negative.
+    pub fn neighbors_8(&self) -> [Coords2D; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .map(|(dx, dy)| Coords2D::new(self.x + dx, self.y + dy))
+    }
+
+    /// Returns all integer points from this point to `other` (inclusive), or `None` if the two
+    /// points are not on the same horizontal or vertical line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use advent_of_code_2025::coords::Coords2D;
+    /// assert_eq!(
+    ///     Coords2D::new(1, 5).line_to(&Coords2D::new(1, 3)),
+    ///     Some(vec![Coords2D::new(1, 5), Coords2D::new(1, 4), Coords2D::new(1, 3)])
+    /// );
+    /// assert_eq!(Coords2D::new(1, 5).line_to(&Coords2D::new(2, 3)), None);
+    /// ```
+    pub fn line_to(&self, other: &Coords2D) -> Option<Vec<Coords2D>> {
+        if self.x != other.x && self.y != other.y {
+            return None;
+        }
+        let (dx, dy) = ((other.x - self.x).signum(), (other.y - self.y).signum());
+        let steps = self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) as i64;
+        let points = (0..=steps)
+            .map(|i| Coords2D::new(self.x + dx * i, self.y + dy * i))
+            .collect();
+        Some(points)
+    }
+}
+
+/// Computes the mean position of the `coords`, or `None` if `coords` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use advent_of_code_2025::coords::{Coords2D, centroid};
+/// let coords = [Coords2D::new(0, 0), Coords2D::new(3, 0), Coords2D::new(0, 3)];
+/// assert_eq!(centroid(&coords), Some((1.0, 1.0)));
+/// ```
+pub fn centroid(coords: &[Coords2D]) -> Option<(f64, f64)> {
+    if coords.is_empty() {
+        return None;
+    }
+    let count = coords.len() as f64;
+    let (sum_x, sum_y) = coords
+        .iter()
+        .fold((0.0, 0.0), |(x, y), c| (x + c.x as f64, y + c.y as f64));
+    Some((sum_x / count, sum_y / count))
+}
+
+impl Add for &Coords2D {
+    type Output = Coords2D;
+
+    fn add(self, other: &Coords2D) -> Coords2D {
+        Coords2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add for Coords2D {
+    type Output = Coords2D;
+
+    fn add(self, other: Coords2D) -> Coords2D {
+        &self + &other
+    }
+}
+
+impl Sub for &Coords2D {
+    type Output = Coords2D;
+
+    fn sub(self, other: &Coords2D) -> Coords2D {
+        Coords2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Sub for Coords2D {
+    type Output = Coords2D;
+
+    fn sub(self, other: Coords2D) -> Coords2D {
+        &self - &other
+    }
+}
+
+impl AddAssign<&Coords2D> for Coords2D {
+    fn add_assign(&mut self, other: &Coords2D) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl AddAssign for Coords2D {
+    fn add_assign(&mut self, other: Coords2D) {
+        *self += &other;
+    }
+}
+
+impl SubAssign<&Coords2D> for Coords2D {
+    fn sub_assign(&mut self, other: &Coords2D) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl SubAssign for Coords2D {
+    fn sub_assign(&mut self, other: Coords2D) {
+        *self -= &other;
+    }
+}
+
+/// Scales both axes by a scalar.
+impl Mul<i64> for &Coords2D {
+    type Output = Coords2D;
+
+    fn mul(self, scalar: i64) -> Coords2D {
+        Coords2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+/// Scales both axes by a scalar.
+impl Mul<i64> for Coords2D {
+    type Output = Coords2D;
+
+    fn mul(self, scalar: i64) -> Coords2D {
+        &self * scalar
+    }
 }
 
 impl Debug for Coords2D {
@@ -20,3 +195,263 @@ impl Debug for Coords2D {
         write!(f, "Coords2D({}, {})", self.x, self.y)
     }
 }
+
+impl From<(i64, i64)> for Coords2D {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Coords2D> for (i64, i64) {
+    fn from(coords: Coords2D) -> Self {
+        (coords.x, coords.y)
+    }
+}
+
+/// Parses `"x,y"` to a [Coords2D].
+///
+/// ```
+/// # use advent_of_code_2025::coords::Coords2D;
+/// assert_eq!("162, -817".parse(), Ok(Coords2D::new(162, -817)));
+/// ```
+impl FromStr for Coords2D {
+    type Err = ParseCoordsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_comma_separated(s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_centroid() {
+        let square = [
+            Coords2D::new(1, 1),
+            Coords2D::new(5, 1),
+            Coords2D::new(5, 5),
+            Coords2D::new(1, 5),
+        ];
+        assert_eq!(centroid(&square), Some((3.0, 3.0)));
+
+        let square = [
+            Coords2D::new(0, 0),
+            Coords2D::new(1, 0),
+            Coords2D::new(1, -1),
+            Coords2D::new(0, -1),
+        ];
+        assert_eq!(centroid(&square), Some((0.5, -0.5)));
+
+        assert_eq!(centroid(&[Coords2D::new(-7, 3)]), Some((-7.0, 3.0)));
+        assert_eq!(centroid(&[]), None);
+    }
+
+    #[test]
+    fn test_distance() {
+        let origin = Coords2D::new(0, 0);
+        assert_eq!(origin.distance(&origin), 0_f64);
+        assert_eq!(origin.distance(&Coords2D::new(3, 4)), 5_f64);
+        assert_eq!(
+            Coords2D::new(-1, -2).distance(&Coords2D::new(1, 1)),
+            13_f64.sqrt()
+        );
+        assert_eq!(
+            Coords2D::new(1, 2).distance(&Coords2D::new(4, 6)),
+            Coords2D::new(4, 6).distance(&Coords2D::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coords2D::new(1, 2);
+        assert_eq!(a.manhattan_distance(&a), 0);
+        assert_eq!(a.manhattan_distance(&Coords2D::new(4, 6)), 7);
+        assert_eq!(Coords2D::new(4, 6).manhattan_distance(&a), 7);
+        assert_eq!(Coords2D::new(-3, -4).manhattan_distance(&a), 10);
+        assert_eq!(
+            Coords2D::new(-3, 4).manhattan_distance(&Coords2D::new(3, -4)),
+            14
+        );
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        let a = Coords2D::new(1, 2);
+        assert_eq!(a.chebyshev_distance(&a), 0);
+        assert_eq!(a.chebyshev_distance(&Coords2D::new(4, 6)), 4);
+        assert_eq!(Coords2D::new(4, 6).chebyshev_distance(&a), 4);
+        assert_eq!(Coords2D::new(-3, -4).chebyshev_distance(&a), 6);
+        assert_eq!(
+            Coords2D::new(-3, 4).chebyshev_distance(&Coords2D::new(3, -4)),
+            8
+        );
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Coords2D::new(3, -4);
+        let b = Coords2D::new(-1, 7);
+
+        assert_eq!(&a + &b, Coords2D::new(2, 3));
+        assert_eq!(&a - &b, Coords2D::new(4, -11));
+        assert_eq!(&b - &a, Coords2D::new(-4, 11));
+        assert_eq!(a.clone() + b.clone(), Coords2D::new(2, 3));
+        assert_eq!(a.clone() - b.clone(), Coords2D::new(4, -11));
+        assert_eq!(&a + &Coords2D::default(), a);
+
+        assert_eq!(&a * 3, Coords2D::new(9, -12));
+        assert_eq!(a.clone() * -1, Coords2D::new(-3, 4));
+
+        let mut c = a.clone();
+        c += &b;
+        assert_eq!(c, Coords2D::new(2, 3));
+        c -= &b;
+        assert_eq!(c, a);
+        c += b.clone();
+        c -= b.clone();
+        assert_eq!(c, a);
+
+        // Same as normal `i64` arithmetic
+        let big = Coords2D::new(i64::MAX - 1, i64::MIN + 1);
+        assert_eq!(
+            &big + &Coords2D::new(1, -1),
+            Coords2D::new(i64::MAX, i64::MIN)
+        );
+        assert_eq!(&big - &big, Coords2D::default());
+    }
+
+    #[test]
+    fn test_neighbors_4() {
+        assert_eq!(
+            Coords2D::new(0, 0).neighbors_4(),
+            [
+                Coords2D::new(0, -1),
+                Coords2D::new(1, 0),
+                Coords2D::new(0, 1),
+                Coords2D::new(-1, 0),
+            ]
+        );
+        assert_eq!(
+            Coords2D::new(5, -3).neighbors_4(),
+            [
+                Coords2D::new(5, -4),
+                Coords2D::new(6, -3),
+                Coords2D::new(5, -2),
+                Coords2D::new(4, -3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors_8() {
+        assert_eq!(
+            Coords2D::new(0, 0).neighbors_8(),
+            [
+                Coords2D::new(0, -1),
+                Coords2D::new(1, -1),
+                Coords2D::new(1, 0),
+                Coords2D::new(1, 1),
+                Coords2D::new(0, 1),
+                Coords2D::new(-1, 1),
+                Coords2D::new(-1, 0),
+                Coords2D::new(-1, -1),
+            ]
+        );
+
+        let center = Coords2D::new(5, -3);
+        let neighbors = center.neighbors_8();
+        assert_eq!(neighbors[0], Coords2D::new(5, -4));
+        assert_eq!(neighbors[3], Coords2D::new(6, -2));
+        assert_eq!(neighbors[7], Coords2D::new(4, -4));
+        assert!(neighbors.iter().all(|n| n.chebyshev_distance(&center) == 1));
+        // Orthogonal neighbors are at even indexes
+        assert_eq!(
+            neighbors.iter().step_by(2).cloned().collect::<Vec<_>>(),
+            center.neighbors_4()
+        );
+    }
+
+    #[test]
+    fn test_line_to() {
+        // Horizontal
+        assert_eq!(
+            Coords2D::new(-1, 2).line_to(&Coords2D::new(2, 2)),
+            Some(vec![
+                Coords2D::new(-1, 2),
+                Coords2D::new(0, 2),
+                Coords2D::new(1, 2),
+                Coords2D::new(2, 2),
+            ])
+        );
+        assert_eq!(
+            Coords2D::new(2, 2).line_to(&Coords2D::new(0, 2)),
+            Some(vec![
+                Coords2D::new(2, 2),
+                Coords2D::new(1, 2),
+                Coords2D::new(0, 2)
+            ])
+        );
+
+        // Vertical
+        assert_eq!(
+            Coords2D::new(3, 0).line_to(&Coords2D::new(3, 2)),
+            Some(vec![
+                Coords2D::new(3, 0),
+                Coords2D::new(3, 1),
+                Coords2D::new(3, 2)
+            ])
+        );
+
+        // Single point
+        assert_eq!(
+            Coords2D::new(3, 4).line_to(&Coords2D::new(3, 4)),
+            Some(vec![Coords2D::new(3, 4)])
+        );
+
+        // Diagonal
+        assert_eq!(Coords2D::new(0, 0).line_to(&Coords2D::new(2, 2)), None);
+        assert_eq!(Coords2D::new(0, 0).line_to(&Coords2D::new(1, 5)), None);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(Coords2D::from((3, -4)), Coords2D::new(3, -4));
+        assert_eq!(<(i64, i64)>::from(Coords2D::new(3, -4)), (3, -4));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1,2".parse(), Ok(Coords2D::new(1, 2)));
+        assert_eq!(" -1 , -20 ".parse(), Ok(Coords2D::new(-1, -20)));
+
+        assert_eq!(
+            "12".parse::<Coords2D>(),
+            Err(ParseCoordsError::WrongValueCount {
+                input: "12".into(),
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            "1,2,3".parse::<Coords2D>(),
+            Err(ParseCoordsError::WrongValueCount {
+                input: "1,2,3".into(),
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            "1,2.5".parse::<Coords2D>(),
+            Err(ParseCoordsError::InvalidInteger {
+                input: "1,2.5".into(),
+                value: "2.5".into()
+            })
+        );
+        assert!("a,2".parse::<Coords2D>().is_err());
+        assert!(",".parse::<Coords2D>().is_err());
+    }
+}
@@ -1,7 +1,15 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::coords::parse_error::{ParseCoordsError, parse_comma_separated};
 
 /// Represents a 3D coordinate.
-#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+///
+/// Coordinates are ordered by `x`, then `y`, then `z`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Coords3D {
     pub x: i64,
     pub y: i64,
@@ -9,6 +17,9 @@ pub struct Coords3D {
 }
 
 impl Coords3D {
+    /// Number of bits used by each axis in [Self::packed_key].
+    pub const PACKED_AXIS_BITS: u32 = 42;
+
     #[must_use]
     #[inline]
     pub fn new(x: i64, y: i64, z: i64) -> Self {
@@ -22,6 +33,138 @@ impl Coords3D {
             (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2);
         (dist as f64).sqrt()
     }
+
+    /// Computes the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) with
+    /// another coordinate, i.e. the number of steps when moving along the 3 axes.
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns the 6 axis-adjacent coordinates in the order: `-x`, `+x`, `-y`, `+y`, `-z`, `+z`.
+    ///
+    /// Bounds are not checked, so the coordinates may be negative.
+    pub fn neighbors_6(&self) -> [Coords3D; 6] {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .map(|delta| *self + Coords3D::from(delta))
+    }
+
+    /// Packs the coordinate into a single integer, which is cheaper to hash than the 3 separate
+    /// axes. Useful as a fast hash map key.
+    ///
+    /// Each axis is stored in [Self::PACKED_AXIS_BITS] bits, so it returns `None` if any axis is
+    /// outside the range `-2^41..2^41`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use advent_of_code_2025::coords::Coords3D;
+    /// let coords = Coords3D::new(162, -817, 812);
+    /// let key = coords.packed_key().unwrap();
+    /// assert_eq!(Coords3D::from_packed_key(key), coords);
+    ///
+    /// assert_eq!(Coords3D::new(i64::MAX, 0, 0).packed_key(), None);
+    /// ```
+    pub fn packed_key(&self) -> Option<i128> {
+        let offset = 1_i128 << (Self::PACKED_AXIS_BITS - 1);
+        let pack_axis = |value: i64| -> Option<i128> {
+            let shifted = value as i128 + offset;
+            (0..1 << Self::PACKED_AXIS_BITS)
+                .contains(&shifted)
+                .then_some(shifted)
+        };
+        let (x, y, z) = (pack_axis(self.x)?, pack_axis(self.y)?, pack_axis(self.z)?);
+        Some((x << (2 * Self::PACKED_AXIS_BITS)) | (y << Self::PACKED_AXIS_BITS) | z)
+    }
+
+    /// Unpacks a key created by [Self::packed_key] back to the coordinate.
+    pub fn from_packed_key(key: i128) -> Self {
+        let offset = 1_i128 << (Self::PACKED_AXIS_BITS - 1);
+        let mask = (1_i128 << Self::PACKED_AXIS_BITS) - 1;
+        let unpack_axis = |shift: u32| (((key >> shift) & mask) - offset) as i64;
+        Self::new(
+            unpack_axis(2 * Self::PACKED_AXIS_BITS),
+            unpack_axis(Self::PACKED_AXIS_BITS),
+            unpack_axis(0),
+        )
+    }
+}
+
+/// Finds all `candidates` whose [Euclidean distance](Coords3D::distance) from `center` is at most
+/// `radius`, in the same order as `candidates`.
+///
+/// # Example
+///
+/// ```
+/// # use advent_of_code_2025::coords::{Coords3D, within_radius};
+/// let center = Coords3D::new(0, 0, 0);
+/// let candidates = [Coords3D::new(1, 0, 0), Coords3D::new(3, 4, 0), Coords3D::new(5, 5, 5)];
+/// assert_eq!(within_radius(&center, 5.0, &candidates), candidates[..2].to_vec());
+/// ```
+pub fn within_radius(center: &Coords3D, radius: f64, candidates: &[Coords3D]) -> Vec<Coords3D> {
+    candidates
+        .iter()
+        .filter(|candidate| center.distance(candidate) <= radius)
+        .copied()
+        .collect()
+}
+
+/// Computes the mean position of the `coords`, or `None` if `coords` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use advent_of_code_2025::coords::{Coords3D, centroid_3d};
+/// let coords = [Coords3D::new(0, 0, 0), Coords3D::new(2, 4, 6)];
+/// assert_eq!(centroid_3d(&coords), Some((1.0, 2.0, 3.0)));
+/// ```
+pub fn centroid_3d(coords: &[Coords3D]) -> Option<(f64, f64, f64)> {
+    if coords.is_empty() {
+        return None;
+    }
+    let count = coords.len() as f64;
+    let (sum_x, sum_y, sum_z) = coords.iter().fold((0.0, 0.0, 0.0), |(x, y, z), c| {
+        (x + c.x as f64, y + c.y as f64, z + c.z as f64)
+    });
+    Some((sum_x / count, sum_y / count, sum_z / count))
+}
+
+impl Add for Coords3D {
+    type Output = Coords3D;
+
+    fn add(self, other: Coords3D) -> Coords3D {
+        Coords3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Add for &Coords3D {
+    type Output = Coords3D;
+
+    fn add(self, other: &Coords3D) -> Coords3D {
+        *self + *other
+    }
+}
+
+impl Sub for Coords3D {
+    type Output = Coords3D;
+
+    fn sub(self, other: Coords3D) -> Coords3D {
+        Coords3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Sub for &Coords3D {
+    type Output = Coords3D;
+
+    fn sub(self, other: &Coords3D) -> Coords3D {
+        *self - *other
+    }
 }
 
 impl Debug for Coords3D {
@@ -30,6 +173,33 @@ impl Debug for Coords3D {
     }
 }
 
+impl From<(i64, i64, i64)> for Coords3D {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Coords3D> for (i64, i64, i64) {
+    fn from(coords: Coords3D) -> Self {
+        (coords.x, coords.y, coords.z)
+    }
+}
+
+/// Parses `"x,y,z"` to a [Coords3D].
+///
+/// ```
+/// # use advent_of_code_2025::coords::Coords3D;
+/// assert_eq!("162,-817,812".parse(), Ok(Coords3D::new(162, -817, 812)));
+/// ```
+impl FromStr for Coords3D {
+    type Err = ParseCoordsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_comma_separated(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,4 +236,159 @@ mod tests {
             Coords3D::new(4, 5, 6).distance(&Coords3D::new(1, 2, 3))
         );
     }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coords3D::new(1, 2, 3);
+        assert_eq!(a.manhattan_distance(&a), 0);
+        assert_eq!(a.manhattan_distance(&Coords3D::new(4, 6, 3)), 7);
+        assert_eq!(Coords3D::new(-3, -4, -5).manhattan_distance(&a), 18);
+        assert_eq!(
+            Coords3D::new(-3, 4, 0).manhattan_distance(&Coords3D::new(3, -4, 2)),
+            Coords3D::new(3, -4, 2).manhattan_distance(&Coords3D::new(-3, 4, 0))
+        );
+    }
+
+    #[test]
+    fn test_neighbors_6() {
+        assert_eq!(
+            Coords3D::new(0, 0, 0).neighbors_6(),
+            [
+                Coords3D::new(-1, 0, 0),
+                Coords3D::new(1, 0, 0),
+                Coords3D::new(0, -1, 0),
+                Coords3D::new(0, 1, 0),
+                Coords3D::new(0, 0, -1),
+                Coords3D::new(0, 0, 1),
+            ]
+        );
+        assert_eq!(
+            Coords3D::new(-5, 2, -8).neighbors_6(),
+            [
+                Coords3D::new(-6, 2, -8),
+                Coords3D::new(-4, 2, -8),
+                Coords3D::new(-5, 1, -8),
+                Coords3D::new(-5, 3, -8),
+                Coords3D::new(-5, 2, -9),
+                Coords3D::new(-5, 2, -7),
+            ]
+        );
+
+        let origin = Coords3D::default();
+        assert!(
+            origin
+                .neighbors_6()
+                .iter()
+                .all(|neighbor| neighbor.manhattan_distance(&origin) == 1)
+        );
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = Coords3D::new(1, -2, 3);
+        let b = Coords3D::new(-4, 5, 6);
+        assert_eq!(a + b, Coords3D::new(-3, 3, 9));
+        assert_eq!(a - b, Coords3D::new(5, -7, -3));
+        assert_eq!(b - a, Coords3D::new(-5, 7, 3));
+        assert_eq!(a + b - b, a);
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let center = Coords3D::new(1, 1, 1);
+        let inside = [Coords3D::new(1, 1, 1), Coords3D::new(2, 2, 2)];
+        let on = [Coords3D::new(1, 1, 4), Coords3D::new(3, 3, 2)];
+        let outside = [Coords3D::new(1, 1, 5), Coords3D::new(-2, -2, -2)];
+        let candidates = [inside[0], outside[0], on[0], inside[1], outside[1], on[1]];
+
+        assert_eq!(
+            within_radius(&center, 3.0, &candidates),
+            vec![inside[0], on[0], inside[1], on[1]]
+        );
+        assert_eq!(within_radius(&center, 0.0, &candidates), vec![inside[0]]);
+        assert_eq!(within_radius(&center, 3.0, &[]), vec![]);
+    }
+
+    #[test]
+    fn test_centroid_3d() {
+        // Corners of a cube
+        let cube: Vec<Coords3D> = itertools::iproduct!([0, 2], [0, 2], [0, 2])
+            .map(|(x, y, z)| Coords3D::new(x, y, z))
+            .collect();
+        assert_eq!(centroid_3d(&cube), Some((1.0, 1.0, 1.0)));
+
+        assert_eq!(
+            centroid_3d(&[Coords3D::new(162, -817, 812)]),
+            Some((162.0, -817.0, 812.0))
+        );
+        assert_eq!(centroid_3d(&[]), None);
+    }
+
+    #[test]
+    fn test_packed_key() {
+        let min = -(1_i64 << 41);
+        let max = (1_i64 << 41) - 1;
+
+        for coords in [
+            Coords3D::new(0, 0, 0),
+            Coords3D::new(1, 2, 3),
+            Coords3D::new(-1, -2, -3),
+            Coords3D::new(162, -817, 812),
+            Coords3D::new(min, max, 0),
+            Coords3D::new(max, min, max),
+        ] {
+            let key = coords.packed_key();
+            assert!(key.is_some(), "{coords:?} should be packable");
+            assert_eq!(Coords3D::from_packed_key(key.unwrap()), coords);
+        }
+
+        assert_ne!(
+            Coords3D::new(1, 2, 3).packed_key(),
+            Coords3D::new(3, 2, 1).packed_key()
+        );
+
+        assert_eq!(Coords3D::new(max + 1, 0, 0).packed_key(), None);
+        assert_eq!(Coords3D::new(0, min - 1, 0).packed_key(), None);
+        assert_eq!(Coords3D::new(0, 0, i64::MIN).packed_key(), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1,2,3".parse(), Ok(Coords3D::new(1, 2, 3)));
+        assert_eq!("-1,0,-3".parse(), Ok(Coords3D::new(-1, 0, -3)));
+
+        assert_eq!(" 1 ,2,  -3 ".parse(), Ok(Coords3D::new(1, 2, -3)));
+
+        assert!("1,2".parse::<Coords3D>().is_err());
+        assert!("1,2,a".parse::<Coords3D>().is_err());
+        assert_eq!(
+            "1,2,3,4".parse::<Coords3D>(),
+            Err(ParseCoordsError::WrongValueCount {
+                input: "1,2,3,4".into(),
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            "1,2,3,".parse::<Coords3D>(),
+            Err(ParseCoordsError::WrongValueCount {
+                input: "1,2,3,".into(),
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            "1,2,".parse::<Coords3D>(),
+            Err(ParseCoordsError::InvalidInteger {
+                input: "1,2,".into(),
+                value: "".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(Coords3D::from((1, -2, 3)), Coords3D::new(1, -2, 3));
+        assert_eq!(<(i64, i64, i64)>::from(Coords3D::new(1, -2, 3)), (1, -2, 3));
+    }
 }
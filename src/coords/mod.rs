@@ -1,7 +1,9 @@
 pub mod compressed_coords_2d;
 pub mod coords_2d;
 pub mod coords_3d;
+pub mod parse_error;
 
 pub use compressed_coords_2d::*;
 pub use coords_2d::*;
 pub use coords_3d::*;
+pub use parse_error::ParseCoordsError;
@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+/// Error when parsing a comma-separated coordinate string like `"1,2,3"`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseCoordsError {
+    #[error("expected {expected} comma-separated values in '{input}', but found {found}")]
+    WrongValueCount {
+        input: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error("invalid integer '{value}' in '{input}'")]
+    InvalidInteger { input: String, value: String },
+}
+
+/// Parses `N` comma-separated integers. Whitespaces around each integer are ignored.
+pub(crate) fn parse_comma_separated<const N: usize>(
+    input: &str,
+) -> Result<[i64; N], ParseCoordsError> {
+    let values: Vec<&str> = input.split(',').map(str::trim).collect();
+    let values: [&str; N] =
+        values
+            .try_into()
+            .map_err(|values: Vec<&str>| ParseCoordsError::WrongValueCount {
+                input: input.into(),
+                expected: N,
+                found: values.len(),
+            })?;
+
+    let mut output = [0; N];
+    for (output, value) in output.iter_mut().zip(values) {
+        *output = value
+            .parse()
+            .map_err(|_| ParseCoordsError::InvalidInteger {
+                input: input.into(),
+                value: value.into(),
+            })?;
+    }
+    Ok(output)
+}
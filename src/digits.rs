@@ -0,0 +1,158 @@
+//! Utilities for finding patterns in the digits of a number.
+
+/// Checks whether the string consists of some block repeated at least twice (e.g. `123123123` is
+/// `123` x3).
+///
+/// Algorithm: Start from left-most character, gradually increase the length of the block to search.
+///
+/// Example: `123123123`:
+///
+/// ```txt
+/// Search '1' from '123123123'
+///   Search '1' in '2', match = false
+/// Search '12' from '123123123'
+///   Skip search '12' in '3123123' since length of remaining substr not divisible by 2
+/// Search '123' from '123123123'
+///   Search '123' in '123', match = true
+///   Search '123' in '123', match = true
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::digits::has_repeating_block;
+///
+/// assert!(has_repeating_block("123123123"));
+/// assert!(has_repeating_block("abab"));
+/// assert!(!has_repeating_block("1231"));
+/// ```
+pub fn has_repeating_block(s: &str) -> bool {
+    repeating_unit(s).is_some()
+}
+
+/// Finds the shortest block that makes up `s` when repeated at least twice, or `None` if there's
+/// none. See [has_repeating_block] for the algorithm.
+///
+/// e.g. `"121212"` -> `Some("12")`, `"1234"` -> `None`
+pub fn repeating_unit(s: &str) -> Option<&str> {
+    (1..s.len())
+        .filter(|&block_len| s.is_char_boundary(block_len))
+        .find(|&block_len| is_repeated_block(s, block_len))
+        .map(|block_len| &s[..block_len])
+}
+
+/// Checks whether `s` consists of its first `block_len` bytes repeated (e.g. `123123` with
+/// `block_len = 3`).
+fn is_repeated_block(s: &str, block_len: usize) -> bool {
+    let rest_len = s.len() - block_len;
+    if !rest_len.is_multiple_of(block_len) {
+        return false;
+    }
+
+    let block = &s[..block_len];
+    (0..rest_len / block_len).all(|round| {
+        let start_index = block_len + block_len * round;
+        let sub_str = &s[start_index..start_index + block_len];
+        block == sub_str
+    })
+}
+
+/// Checks whether the digits of `number` written in base `radix` consist of some digit block
+/// repeated at least twice. See [has_repeating_block].
+///
+/// # Panic
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::digits::is_repeating_in_radix;
+///
+/// assert!(is_repeating_in_radix(0b101101, 2));
+/// assert!(is_repeating_in_radix(0xABAB, 16));
+/// assert!(!is_repeating_in_radix(0xABAB, 10));
+/// ```
+pub fn is_repeating_in_radix(number: u64, radix: u32) -> bool {
+    has_repeating_block(&to_radix_string(number, radix))
+}
+
+/// Writes the number in base `radix` with lowercase letters for digits above 9 (e.g. `0xABAB` in
+/// base 16 is `"abab"`).
+fn to_radix_string(mut number: u64, radix: u32) -> String {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix should be in 2..=36, but got {radix}"
+    );
+    let mut digits = vec![];
+    loop {
+        let digit = (number % radix as u64) as u32;
+        digits.push(char::from_digit(digit, radix).expect("digit is smaller than radix"));
+        number /= radix as u64;
+        if number == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_has_repeating_block() {
+        assert!(has_repeating_block("11"));
+        assert!(has_repeating_block("6464"));
+        assert!(has_repeating_block("123123123"));
+        assert!(has_repeating_block("1188511885"));
+        assert!(has_repeating_block("ééé"));
+
+        assert!(!has_repeating_block("7"));
+        assert!(!has_repeating_block("1231"));
+        assert!(!has_repeating_block("12341234123"));
+        assert!(!has_repeating_block(""));
+    }
+
+    #[test]
+    fn test_repeating_unit() {
+        assert_eq!(repeating_unit("121212"), Some("12"));
+        assert_eq!(repeating_unit("111111"), Some("1"));
+        assert_eq!(repeating_unit("abcabc"), Some("abc"));
+        assert_eq!(repeating_unit("éé"), Some("é"));
+        assert_eq!(repeating_unit("123123"), Some("123"));
+        assert_eq!(repeating_unit("1234"), None);
+        assert_eq!(repeating_unit("7"), None);
+    }
+
+    #[test]
+    fn test_is_repeating_in_radix() {
+        // `101` x2
+        assert!(is_repeating_in_radix(0b101101, 2));
+        assert!(!is_repeating_in_radix(0b101100, 2));
+        // `ab` x2
+        assert!(is_repeating_in_radix(0xABAB, 16));
+        assert!(!is_repeating_in_radix(0xABAC, 16));
+        // `0xABAB` is `43947` in decimal
+        assert!(!is_repeating_in_radix(0xABAB, 10));
+
+        assert!(is_repeating_in_radix(123123, 10));
+        assert!(!is_repeating_in_radix(0, 10));
+        assert!(is_repeating_in_radix(u64::MAX, 2));
+    }
+
+    #[test]
+    fn test_to_radix_string() {
+        assert_eq!(to_radix_string(0, 2), "0");
+        assert_eq!(to_radix_string(0b101101, 2), "101101");
+        assert_eq!(to_radix_string(0xABAB, 16), "abab");
+        assert_eq!(to_radix_string(u64::MAX, 36), "3w5e11264sgsf");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_radix_string_invalid_radix() {
+        to_radix_string(10, 37);
+    }
+}
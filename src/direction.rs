@@ -0,0 +1,124 @@
+//! Compass directions for walking on a grid.
+
+/// One of the 4 compass directions on a grid.
+///
+/// Follows the same convention as the puzzle inputs, where `(0, 0)` is the top left corner, x
+/// increases rightward and y increases downward. So north is up and east is right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting from [Direction::North].
+    pub fn all() -> [Direction; 4] {
+        [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+    }
+
+    /// Gets the `(dx, dy)` of moving one step in this direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use advent_of_code_2025::Direction;
+    ///
+    /// assert_eq!(Direction::North.delta(), (0, -1));
+    /// assert_eq!(Direction::East.delta(), (1, 0));
+    /// ```
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Turns 180 degrees.
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(Direction::North.delta(), (0, -1));
+        assert_eq!(Direction::South.delta(), (0, 1));
+        assert_eq!(Direction::East.delta(), (1, 0));
+        assert_eq!(Direction::West.delta(), (-1, 0));
+
+        for direction in Direction::all() {
+            let (dx, dy) = direction.delta();
+            assert_eq!(direction.opposite().delta(), (-dx, -dy));
+        }
+    }
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_left(), Direction::South);
+        assert_eq!(Direction::South.turn_left(), Direction::East);
+        assert_eq!(Direction::East.turn_left(), Direction::North);
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::East.turn_right(), Direction::South);
+        assert_eq!(Direction::South.turn_right(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::South.opposite(), Direction::North);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Direction::West.opposite(), Direction::East);
+
+        for direction in Direction::all() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        let all = Direction::all();
+        for (i, direction) in all.iter().enumerate() {
+            assert_eq!(direction.turn_right(), all[(i + 1) % 4]);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Shortest path algorithms over implicit graphs, where neighbors are computed on demand.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, hash_map::Entry},
+    hash::Hash,
+};
+
+/// Finds the cheapest path from `start` to the first node satisfying `is_goal`, using
+/// [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+///
+/// `neighbors(node)` yields the successors of `node` as `(successor, cost)` pairs, so the graph can
+/// be directed and is only explored as far as needed.
+///
+/// Returns the total cost and the path from `start` to the goal (both inclusive), or `None` if no
+/// goal is reachable. If `start` is a goal, the path is just `[start]` with cost `0`.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::graph::dijkstra;
+///
+/// // Each number `n` can go to `n + 1` for a cost of 2, or to `n * 2` for a cost of 3
+/// let result = dijkstra(1_u64, |&n| [(n + 1, 2), (n * 2, 3)], |&n| n == 8);
+/// assert_eq!(result, Some((8, vec![1, 2, 4, 8])));
+/// ```
+pub fn dijkstra<N, I, FN>(
+    start: N,
+    mut neighbors: FN,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+    FN: FnMut(&N) -> I,
+{
+    // Nodes are identified by their index in `nodes`, so the heap doesn't need `N: Ord`
+    let mut nodes: Vec<N> = vec![start.clone()];
+    let mut index_of: HashMap<N, usize> = HashMap::from([(start, 0)]);
+    let mut costs: Vec<u64> = vec![0];
+    let mut previous: Vec<Option<usize>> = vec![None];
+
+    let mut heap = BinaryHeap::from([Reverse((0_u64, 0_usize))]);
+    while let Some(Reverse((cost, idx))) = heap.pop() {
+        if cost > costs[idx] {
+            continue; // stale entry, a cheaper path to this node was found already
+        }
+        if is_goal(&nodes[idx]) {
+            return Some((cost, build_path(&nodes, &previous, idx)));
+        }
+
+        for (next, step_cost) in neighbors(&nodes[idx]) {
+            let next_cost = cost + step_cost;
+            let next_idx = match index_of.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_idx = *entry.get();
+                    if next_cost >= costs[next_idx] {
+                        continue;
+                    }
+                    costs[next_idx] = next_cost;
+                    previous[next_idx] = Some(idx);
+                    next_idx
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    costs.push(next_cost);
+                    previous.push(Some(idx));
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+            heap.push(Reverse((next_cost, next_idx)));
+        }
+    }
+
+    None
+}
+
+/// Follows `previous` back from the node at `end_idx` to the start, and returns the nodes from the
+/// start to the end.
+fn build_path<N: Clone>(nodes: &[N], previous: &[Option<usize>], end_idx: usize) -> Vec<N> {
+    let mut path = vec![nodes[end_idx].clone()];
+    let mut idx = end_idx;
+    while let Some(prev_idx) = previous[idx] {
+        path.push(nodes[prev_idx].clone());
+        idx = prev_idx;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// ```txt
+    ///      1      1
+    ///   A ──> B ────> D ──┐ 1
+    ///   │     │ 5     ▲   ▼
+    ///   │ 4   └─> E   │   F
+    ///   └───> C ──────┘
+    ///             2
+    ///   G (no edges)
+    /// ```
+    fn tiny_graph(node: &char) -> Vec<(char, u64)> {
+        match node {
+            'A' => vec![('B', 1), ('C', 4)],
+            'B' => vec![('D', 1), ('E', 5)],
+            'C' => vec![('D', 2)],
+            'D' => vec![('F', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra('A', tiny_graph, |&node| node == 'F'),
+            Some((3, vec!['A', 'B', 'D', 'F']))
+        );
+        assert_eq!(
+            dijkstra('A', tiny_graph, |&node| node == 'C'),
+            Some((4, vec!['A', 'C']))
+        );
+        assert_eq!(
+            dijkstra('A', tiny_graph, |&node| node == 'E'),
+            Some((6, vec!['A', 'B', 'E']))
+        );
+        assert_eq!(
+            dijkstra('C', tiny_graph, |&node| node == 'F'),
+            Some((3, vec!['C', 'D', 'F']))
+        );
+
+        // Start is the goal
+        assert_eq!(
+            dijkstra('A', tiny_graph, |&node| node == 'A'),
+            Some((0, vec!['A']))
+        );
+        // First goal reached by cost wins
+        assert_eq!(
+            dijkstra('A', tiny_graph, |&node| node == 'E' || node == 'F'),
+            Some((3, vec!['A', 'B', 'D', 'F']))
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_cheaper_path_found_later() {
+        // A -> B is direct but expensive, A -> C -> B is cheaper
+        let neighbors = |node: &char| match node {
+            'A' => vec![('B', 10), ('C', 1)],
+            'C' => vec![('B', 1)],
+            _ => vec![],
+        };
+        assert_eq!(
+            dijkstra('A', neighbors, |&node| node == 'B'),
+            Some((2, vec!['A', 'C', 'B']))
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        assert_eq!(dijkstra('A', tiny_graph, |&node| node == 'G'), None);
+        // Edges are directed
+        assert_eq!(dijkstra('F', tiny_graph, |&node| node == 'A'), None);
+    }
+}
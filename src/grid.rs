@@ -1,5 +1,14 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+    ops::Range,
+};
+
 use anyhow::Result;
 use grid::*;
+use itertools::{Itertools, MinMaxResult};
+
+use crate::coords::Coords2D;
 
 /// Creates a 2D [Grid] from a string input, where each row is separated by new line. Each character
 /// is parsed by `char_parser` to convert it to type `T`.
@@ -33,14 +42,745 @@ where
     Ok(Grid::from_vec(cells, width))
 }
 
-/// Converts a grid to string.
-pub fn grid_to_string<T: ToString>(grid: &Grid<T>) -> String {
+/// Like [parse_string_to_grid], but `char_mapper` returns `None` for invalid characters instead of
+/// an error. This is handy for simple mappings that don't need their own error message.
+///
+/// The error names the invalid character and its position as 1-based line and column numbers.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::parse_string_to_grid_with;
+/// use grid::*;
+///
+/// let grid = parse_string_to_grid_with(".@\n@.", |ch| match ch {
+///     '@' => Some(true),
+///     '.' => Some(false),
+///     _ => None,
+/// });
+/// assert_eq!(grid.unwrap(), grid![[false, true][true, false]]);
+/// ```
+pub fn parse_string_to_grid_with<T, F>(input: &str, char_mapper: F) -> Result<Grid<T>>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.first().map(|line| line.len()).unwrap_or_default();
+
+    if lines.iter().skip(1).any(|line| line.len() != width) {
+        return Err(anyhow::anyhow!("Width of each line should be equal"));
+    }
+
+    let mut cells: Vec<T> = Vec::with_capacity(lines.len() * width);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            let cell = char_mapper(ch).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid character {ch:?} at line {}, column {}",
+                    row + 1,
+                    col + 1
+                )
+            })?;
+            cells.push(cell);
+        }
+    }
+
+    Ok(Grid::from_vec(cells, width))
+}
+
+/// Converts a grid to string, where rows are separated by new line and cells are not separated.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::grid_to_string;
+/// use grid::*;
+///
+/// assert_eq!(grid_to_string(&grid![[1, 2][3, 4]]), "12\n34");
+/// ```
+pub fn grid_to_string<T: Display>(grid: &Grid<T>) -> String {
     grid.iter_rows()
         .map(|row| row.map(|cell| cell.to_string()).collect::<String>())
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Prints the grid like [grid_to_string]. Used for debugging purposes.
+pub fn print_grid<T: Display>(grid: &Grid<T>) {
+    println!("{}", grid_to_string(grid));
+}
+
+/// Converts a grid to string like [grid_to_string], but prefixes each row with its row index and
+/// adds a header of column indices. Column indices with multiple digits are written vertically.
+///
+/// Each cell is expected to be displayed as a single character.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::grid_to_string_with_rulers;
+/// use grid::*;
+///
+/// let grid = grid![['#', '.', '#']['.', '#', '.']];
+/// assert_eq!(grid_to_string_with_rulers(&grid), "  012\n0 #.#\n1 .#.");
+/// ```
+pub fn grid_to_string_with_rulers<T: Display>(grid: &Grid<T>) -> String {
+    let row_width = grid.rows().saturating_sub(1).to_string().len();
+    let col_width = grid.cols().saturating_sub(1).to_string().len();
+    let padding = " ".repeat(row_width + 1);
+
+    let col_labels: Vec<Vec<char>> = (0..grid.cols())
+        .map(|col| format!("{col:>col_width$}").chars().collect())
+        .collect();
+    let header = (0..col_width).map(|digit| {
+        let digits: String = col_labels.iter().map(|label| label[digit]).collect();
+        format!("{padding}{digits}")
+    });
+    let rows = grid.iter_rows().enumerate().map(|(row, cells)| {
+        let cells: String = cells.map(|cell| cell.to_string()).collect();
+        format!("{row:>row_width$} {cells}")
+    });
+
+    header.chain(rows).join("\n")
+}
+
+/// Replaces all cells equal to `from` with `to`, and returns the number of cells replaced.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::replace_cells;
+/// use grid::*;
+///
+/// let mut grid = grid![['#', '.']['.', '.']];
+/// assert_eq!(replace_cells(&mut grid, &'.', &'O'), 3);
+/// assert_eq!(grid, grid![['#', 'O']['O', 'O']]);
+/// ```
+pub fn replace_cells<T: PartialEq + Clone>(grid: &mut Grid<T>, from: &T, to: &T) -> usize {
+    grid.iter_mut()
+        .filter(|cell| *cell == from)
+        .map(|cell| *cell = to.clone())
+        .count()
+}
+
+/// Compares two grids cell by cell, and lists the `(row, col)` of the differing cells in row-major
+/// order. Returns `None` if the grids have different dimensions.
+///
+/// Useful for checking that an optimized algorithm produces the same grid as a slow reference one.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::grid_symmetric_diff;
+/// use grid::*;
+///
+/// let a = grid![['#', '.']['.', '.']];
+/// assert_eq!(grid_symmetric_diff(&a, &a), Some(vec![]));
+/// assert_eq!(grid_symmetric_diff(&a, &grid![['#', '.']['#', '.']]), Some(vec![(1, 0)]));
+/// assert_eq!(grid_symmetric_diff(&a, &grid![['#', '.']]), None);
+/// ```
+pub fn grid_symmetric_diff<T: PartialEq>(a: &Grid<T>, b: &Grid<T>) -> Option<Vec<(usize, usize)>> {
+    if a.size() != b.size() {
+        return None;
+    }
+    let diff = a
+        .indexed_iter()
+        .filter(|&(pos, cell)| b[pos] != *cell)
+        .map(|(pos, _)| pos)
+        .collect();
+    Some(diff)
+}
+
+/// Returns a copy of the grid rotated 90 degrees clockwise, unlike [Grid::rotate_right] which
+/// rotates in place.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::rotated_right;
+/// use grid::*;
+///
+/// let grid = grid![[1, 2, 3][4, 5, 6]];
+/// assert_eq!(rotated_right(&grid), grid![[4, 1][5, 2][6, 3]]);
+/// ```
+pub fn rotated_right<T: Clone>(grid: &Grid<T>) -> Grid<T> {
+    let mut rotated = grid.clone();
+    rotated.rotate_right();
+    rotated
+}
+
+/// Returns a copy of the grid rotated 90 degrees counter-clockwise, unlike [Grid::rotate_left]
+/// which rotates in place.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::rotated_left;
+/// use grid::*;
+///
+/// let grid = grid![[1, 2, 3][4, 5, 6]];
+/// assert_eq!(rotated_left(&grid), grid![[3, 6][2, 5][1, 4]]);
+/// ```
+pub fn rotated_left<T: Clone>(grid: &Grid<T>) -> Grid<T> {
+    let mut rotated = grid.clone();
+    rotated.rotate_left();
+    rotated
+}
+
+/// Returns a copy of the grid with rows and columns swapped, i.e. the cell at `(row, col)` moves to
+/// `(col, row)`. Unlike [Grid::transpose], it works on non-square grids.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::transpose;
+/// use grid::*;
+///
+/// let grid = grid![[1, 2, 3][4, 5, 6]];
+/// assert_eq!(transpose(&grid), grid![[1, 4][2, 5][3, 6]]);
+/// ```
+pub fn transpose<T: Clone>(grid: &Grid<T>) -> Grid<T> {
+    let cells: Vec<T> = grid.iter_cols().flatten().cloned().collect();
+    Grid::from_vec(cells, grid.rows())
+}
+
+/// Number of columns stored in each word of [grid_row_masks].
+pub const ROW_MASK_WORD_BITS: usize = u128::BITS as usize;
+
+/// Represents each row of the grid as a bitmask, where bit `col` is set if the cell at `(row, col)`
+/// satisfies `is_set`. This makes checking whether a span of cells is all set a few masked
+/// comparisons instead of a loop over each cell (see [span_all_set]).
+///
+/// Grids can be wider than 128 columns, so each row is a list of words. Word `i` holds the columns
+/// `i * 128..(i + 1) * 128`, where the lowest bit is the leftmost column.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::grid_row_masks;
+/// use grid::*;
+///
+/// let grid = grid![['#', '.', '#']['.', '#', '#']];
+/// assert_eq!(grid_row_masks(&grid, |&cell| cell == '#'), vec![vec![0b101], vec![0b110]]);
+/// ```
+pub fn grid_row_masks<T, F: Fn(&T) -> bool>(grid: &Grid<T>, is_set: F) -> Vec<Vec<u128>> {
+    let word_count = grid.cols().div_ceil(ROW_MASK_WORD_BITS);
+    grid.iter_rows()
+        .map(|row| {
+            let mut words = vec![0; word_count];
+            for (col, cell) in row.enumerate() {
+                if is_set(cell) {
+                    words[col / ROW_MASK_WORD_BITS] |= 1 << (col % ROW_MASK_WORD_BITS);
+                }
+            }
+            words
+        })
+        .collect()
+}
+
+/// Checks whether all cells in `row` within `col_range` are set in the masks from
+/// [grid_row_masks]. An empty range is always all set, while a row or columns outside the grid are
+/// never set.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::{grid_row_masks, span_all_set};
+/// use grid::*;
+///
+/// let masks = grid_row_masks(&grid![['#', '.', '#']['.', '#', '#']], |&cell| cell == '#');
+/// assert!(span_all_set(&masks, 1, 1..3));
+/// assert!(!span_all_set(&masks, 0, 0..3));
+/// ```
+pub fn span_all_set(masks: &[Vec<u128>], row: usize, col_range: Range<usize>) -> bool {
+    if col_range.is_empty() {
+        return true;
+    }
+    let Some(words) = masks.get(row) else {
+        return false;
+    };
+
+    let first_word = col_range.start / ROW_MASK_WORD_BITS;
+    let last_word = (col_range.end - 1) / ROW_MASK_WORD_BITS;
+    (first_word..=last_word).all(|word_idx| {
+        let Some(&word) = words.get(word_idx) else {
+            return false;
+        };
+        // Bits of the range within this word
+        let word_start = word_idx * ROW_MASK_WORD_BITS;
+        let low = col_range.start.max(word_start) - word_start;
+        let high = col_range.end.min(word_start + ROW_MASK_WORD_BITS) - word_start;
+        let mask = (u128::MAX >> (ROW_MASK_WORD_BITS - (high - low))) << low;
+        word & mask == mask
+    })
+}
+
+/// Finds every maximal rectangle where all cells satisfy `is_filled`. A rectangle is maximal if it
+/// cannot be extended in any direction without including an unfilled cell.
+///
+/// Each rectangle is returned as `(top_left, bottom_right, area)`, where the corners are
+/// `(row, col)`. The rectangles are sorted in descending order of area.
+///
+/// # Algorithm
+///
+/// Each row is treated as the bottom of a histogram, where each column's bar height is the number
+/// of consecutive filled cells ending at that row. A monotonic stack finds every column range whose
+/// rectangle can't be extended to the left, right or top. The rectangle is kept if it also can't
+/// be extended to the next row. This runs in `O(rows * cols)`.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::all_filled_rectangles;
+/// use grid::*;
+///
+/// // ##
+/// // #.
+/// let grid = grid![[true, true][true, false]];
+/// assert_eq!(
+///     all_filled_rectangles(&grid, |&cell| cell),
+///     vec![((0, 0), (0, 1), 2), ((0, 0), (1, 0), 2)]
+/// );
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn all_filled_rectangles<T, F: Fn(&T) -> bool>(
+    grid: &Grid<T>,
+    is_filled: F,
+) -> Vec<((usize, usize), (usize, usize), usize)> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let mut heights = vec![0_usize; cols];
+    let mut rectangles = vec![];
+
+    for row in 0..rows {
+        for (col, height) in heights.iter_mut().enumerate() {
+            *height = if is_filled(&grid[(row, col)]) {
+                *height + 1
+            } else {
+                0
+            };
+        }
+
+        // `next_row_prefix[col]` = no. of filled cells in the next row before column `col`, used
+        // to check whether a rectangle can be extended downwards
+        let mut next_row_prefix = vec![0_usize; cols + 1];
+        if row + 1 < rows {
+            for col in 0..cols {
+                next_row_prefix[col + 1] =
+                    next_row_prefix[col] + usize::from(is_filled(&grid[(row + 1, col)]));
+            }
+        }
+        let can_extend_down = |start: usize, end: usize| {
+            row + 1 < rows && next_row_prefix[end + 1] - next_row_prefix[start] == end + 1 - start
+        };
+
+        // Stack of `(start_col, height)` with strictly increasing heights
+        let mut stack: Vec<(usize, usize)> = vec![];
+        for col in 0..=cols {
+            let height = heights.get(col).copied().unwrap_or(0); // sentinel at the end
+            let mut start = col;
+            while let Some(&(top_start, top_height)) = stack.last() {
+                if top_height <= height {
+                    break;
+                }
+                stack.pop();
+                if !can_extend_down(top_start, col - 1) {
+                    rectangles.push((
+                        (row + 1 - top_height, top_start),
+                        (row, col - 1),
+                        top_height * (col - top_start),
+                    ));
+                }
+                start = top_start;
+            }
+            if height > 0
+                && stack
+                    .last()
+                    .is_none_or(|&(_, top_height)| top_height < height)
+            {
+                stack.push((start, height));
+            }
+        }
+    }
+
+    rectangles.sort_by_key(|&(_, _, area)| std::cmp::Reverse(area));
+    rectangles
+}
+
+/// Position of the origin `(0, 0)` when rendering coordinates with [render_coords].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Origin {
+    /// The y axis increases downward, like how puzzle inputs are read (e.g. day 9).
+    #[default]
+    TopLeft,
+    /// The y axis increases upward, like a math graph.
+    BottomLeft,
+}
+
+/// Renders the coordinates as a string, where each coordinate is `#` and other cells are `.`.
+/// Only the bounding box of the coordinates is rendered.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::{coords::Coords2D, grid::{Origin, render_coords}};
+///
+/// let coords = [Coords2D::new(0, 0), Coords2D::new(2, 1)];
+/// assert_eq!(render_coords(&coords, Origin::TopLeft), "#..\n..#");
+/// assert_eq!(render_coords(&coords, Origin::BottomLeft), "..#\n#..");
+/// ```
+pub fn render_coords(coords: &[Coords2D], origin: Origin) -> String {
+    let bounds = |values: MinMaxResult<i64>| match values {
+        MinMaxResult::NoElements => (0, -1),
+        MinMaxResult::OneElement(value) => (value, value),
+        MinMaxResult::MinMax(min, max) => (min, max),
+    };
+    let (min_x, max_x) = bounds(coords.iter().map(|coord| coord.x).minmax());
+    let (min_y, max_y) = bounds(coords.iter().map(|coord| coord.y).minmax());
+
+    let rows = (max_y - min_y + 1) as usize;
+    let cols = (max_x - min_x + 1) as usize;
+    let mut grid = Grid::init(rows, cols, '.');
+    coords.iter().for_each(|coord| {
+        grid[((coord.y - min_y) as usize, (coord.x - min_x) as usize)] = '#';
+    });
+
+    if origin == Origin::BottomLeft {
+        grid.flip_rows();
+    }
+    grid_to_string(&grid)
+}
+
+/// Flood fills the region containing `start` in 4 directions, calling `set` on every cell where
+/// `should_fill` is true. Cells outside the grid are never visited, and `start` itself is skipped if
+/// `should_fill` is false for it.
+///
+/// The fill is depth-first using an explicit stack instead of recursion, so large regions can't
+/// overflow the call stack. Neighbors are pushed in the order up, down, left, right, hence the
+/// right neighbor is filled next.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::flood_fill;
+/// use grid::*;
+///
+/// let mut grid = grid![[0, 0, 1][0, 1, 0]];
+/// flood_fill(&mut grid, (0, 0), |&cell| cell == 0, |cell| *cell = 2);
+/// assert_eq!(grid, grid![[2, 2, 1][2, 1, 0]]);
+/// ```
+pub fn flood_fill<T, F, G>(grid: &mut Grid<T>, start: (usize, usize), should_fill: F, set: G)
+where
+    F: Fn(&T) -> bool,
+    G: FnMut(&mut T),
+{
+    flood_fill_with_limit(grid, start, should_fill, set, None);
+}
+
+/// Outcome of [flood_fill_with_limit].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloodFillResult {
+    /// The entire region is filled. Holds the number of filled cells.
+    Completed(usize),
+    /// The region is larger than the limit, so filling stopped early. Holds the number of filled
+    /// cells, which equals the limit.
+    Truncated(usize),
+}
+
+/// Flood fills the region containing `start` in 4 directions, calling `set` on every cell where
+/// `should_fill` is true. Cells outside the grid are never visited.
+///
+/// Filling stops once `max_cells` cells are filled and the region still has more cells to fill, so
+/// pathological inputs can't make it run forever. Pass `None` to fill the whole region.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::{FloodFillResult, flood_fill_with_limit};
+/// use grid::*;
+///
+/// let mut grid = grid![[0, 0, 1][0, 1, 0]];
+/// let result = flood_fill_with_limit(&mut grid, (0, 0), |&cell| cell == 0, |cell| *cell = 2, None);
+/// assert_eq!(result, FloodFillResult::Completed(3));
+/// assert_eq!(grid, grid![[2, 2, 1][2, 1, 0]]);
+/// ```
+pub fn flood_fill_with_limit<T, F, G>(
+    grid: &mut Grid<T>,
+    start: (usize, usize),
+    should_fill: F,
+    mut set: G,
+    max_cells: Option<usize>,
+) -> FloodFillResult
+where
+    F: Fn(&T) -> bool,
+    G: FnMut(&mut T),
+{
+    let mut visited: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    let mut cells_to_fill: Vec<(usize, usize)> = vec![start];
+    let mut filled_count = 0;
+
+    while let Some((row, col)) = cells_to_fill.pop() {
+        let Some(cell) = grid.get_mut(row, col) else {
+            continue;
+        };
+        if visited[(row, col)] || !should_fill(cell) {
+            continue;
+        }
+        if max_cells.is_some_and(|max| filled_count >= max) {
+            return FloodFillResult::Truncated(filled_count);
+        }
+
+        visited[(row, col)] = true;
+        set(cell);
+        filled_count += 1;
+
+        // Out-of-bounds cells (e.g. `row + 1 == grid.rows()`) are skipped when popped
+        cells_to_fill.extend(
+            [
+                row.checked_sub(1).map(|r| (r, col)),
+                Some((row + 1, col)),
+                col.checked_sub(1).map(|c| (row, c)),
+                Some((row, col + 1)),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+    }
+
+    FloodFillResult::Completed(filled_count)
+}
+
+/// Finds the fewest steps from `start` to `goal` using breadth-first search. `neighbors` yields the
+/// passable cells adjacent to the given cell, and cells outside the grid are ignored.
+///
+/// Each cell is visited at most once. Returns `None` if `goal` is unreachable.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::bfs_shortest_path;
+/// use grid::*;
+///
+/// let grid = grid![['.', '#', '.']['.', '.', '.']];
+/// let neighbors = |(row, col): (usize, usize)| {
+///     [(row.wrapping_sub(1), col), (row + 1, col), (row, col.wrapping_sub(1)), (row, col + 1)]
+///         .into_iter()
+///         .filter(|&(r, c)| grid.get(r, c) == Some(&'.'))
+/// };
+/// assert_eq!(bfs_shortest_path(&grid, (0, 0), (0, 2), neighbors), Some(4));
+/// ```
+pub fn bfs_shortest_path<T, N, I>(
+    grid: &Grid<T>,
+    start: (usize, usize),
+    goal: (usize, usize),
+    neighbors: N,
+) -> Option<usize>
+where
+    N: Fn((usize, usize)) -> I,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut visited: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    *visited.get_mut(start.0, start.1)? = true;
+    let mut queue: VecDeque<((usize, usize), usize)> = VecDeque::from([(start, 0)]);
+
+    while let Some((cell, steps)) = queue.pop_front() {
+        if cell == goal {
+            return Some(steps);
+        }
+        for next in neighbors(cell) {
+            match visited.get_mut(next.0, next.1) {
+                Some(seen) if !*seen => {
+                    *seen = true;
+                    queue.push_back((next, steps + 1));
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// Labels every 4-directionally connected region of cells where `is_region` is true. Each region
+/// cell holds the region's ID, and other cells are `None`.
+///
+/// IDs start from `0` and are assigned in row-major order of each region's first cell.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::label_regions;
+/// use grid::*;
+///
+/// let grid = grid![['#', '.', '#']['#', '.', '#']];
+/// assert_eq!(
+///     label_regions(&grid, |&cell| cell == '#'),
+///     grid![[Some(0), None, Some(1)][Some(0), None, Some(1)]]
+/// );
+/// ```
+pub fn label_regions<T, F: Fn(&T) -> bool>(grid: &Grid<T>, is_region: F) -> Grid<Option<usize>> {
+    // Each cell is `(is_region, region_id)`
+    let mut labels: Grid<(bool, Option<usize>)> = Grid::from_vec(
+        grid.iter().map(|cell| (is_region(cell), None)).collect(),
+        grid.cols(),
+    );
+
+    let mut region_count = 0;
+    for row in 0..grid.rows() {
+        for col in 0..grid.cols() {
+            if labels[(row, col)] != (true, None) {
+                continue;
+            }
+            flood_fill_with_limit(
+                &mut labels,
+                (row, col),
+                |&(is_region, id)| is_region && id.is_none(),
+                |(_, id)| *id = Some(region_count),
+                None,
+            );
+            region_count += 1;
+        }
+    }
+
+    Grid::from_vec(labels.iter().map(|&(_, id)| id).collect(), grid.cols())
+}
+
+/// Splits the grid into 4-directionally connected components, where `same_region(a, b)` decides
+/// whether 2 adjacent cells belong together. Unlike [label_regions], every cell belongs to a
+/// component, and a component can hold different values (e.g. digits that differ by at most 1).
+///
+/// Components are in row-major order of their first cell, and the `(row, col)` of each component
+/// are in row-major order.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::connected_components;
+/// use grid::*;
+///
+/// let grid = grid![['#', '.', '#']['#', '.', '.']];
+/// assert_eq!(
+///     connected_components(&grid, |a, b| a == b),
+///     vec![vec![(0, 0), (1, 0)], vec![(0, 1), (1, 1), (1, 2)], vec![(0, 2)]]
+/// );
+/// ```
+pub fn connected_components<T, F>(grid: &Grid<T>, same_region: F) -> Vec<Vec<(usize, usize)>>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut visited: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    let mut components = vec![];
+
+    for (start, _) in grid.indexed_iter() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        let mut component = vec![];
+        let mut cells_to_visit = vec![start];
+        while let Some((row, col)) = cells_to_visit.pop() {
+            component.push((row, col));
+            let neighbors = [
+                row.checked_sub(1).map(|r| (r, col)),
+                Some((row + 1, col)),
+                col.checked_sub(1).map(|c| (row, c)),
+                Some((row, col + 1)),
+            ];
+            for (next_row, next_col) in neighbors.into_iter().flatten() {
+                let Some(next_cell) = grid.get(next_row, next_col) else {
+                    continue;
+                };
+                if !visited[(next_row, next_col)] && same_region(&grid[(row, col)], next_cell) {
+                    visited[(next_row, next_col)] = true;
+                    cells_to_visit.push((next_row, next_col));
+                }
+            }
+        }
+        component.sort_unstable();
+        components.push(component);
+    }
+
+    components
+}
+
+/// Walks along the boundary of the region containing `start` using the
+/// [right-hand rule](https://en.wikipedia.org/wiki/Maze-solving_algorithm#Wall_follower), and returns
+/// the cells visited in order until it's back at `start`. The region consists of cells where
+/// `is_wall` is false, and cells outside the grid are treated as walls.
+///
+/// `start` should be on the boundary, i.e. next to a wall in 4 directions. Otherwise (or if `start`
+/// is a wall), an empty list is returned. Cells of 1-cell-wide parts are visited more than once,
+/// since the walk goes along both sides of them.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::grid::trace_boundary;
+/// use grid::*;
+///
+/// // ###
+/// // #..
+/// // #..
+/// let grid = grid![['#', '#', '#']['#', '.', '.']['#', '.', '.']];
+/// assert_eq!(
+///     trace_boundary(&grid, (1, 1), |&cell| cell == '#'),
+///     vec![(1, 1), (2, 1), (2, 2), (1, 2)]
+/// );
+/// ```
+pub fn trace_boundary<T, F: Fn(&T) -> bool>(
+    grid: &Grid<T>,
+    start: (usize, usize),
+    is_wall: F,
+) -> Vec<(usize, usize)> {
+    // Up, right, down, left (i.e. clockwise), so turning right is `+ 1`
+    const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let step = |(row, col): (usize, usize), dir: usize| -> Option<(usize, usize)> {
+        let (d_row, d_col) = DIRECTIONS[dir];
+        let next = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        grid.get(next.0, next.1)
+            .is_some_and(|cell| !is_wall(cell))
+            .then_some(next)
+    };
+
+    if grid.get(start.0, start.1).is_none_or(&is_wall) {
+        return vec![];
+    }
+    // Face the direction where the wall is on the right hand
+    let Some(wall_dir) = (0..4).find(|&dir| step(start, dir).is_none()) else {
+        return vec![];
+    };
+    let start_dir = (wall_dir + 3) % 4;
+
+    // Tries turning right, going straight, turning left, then turning back
+    let next_move = |cell: (usize, usize), dir: usize| {
+        [1, 0, 3, 2].iter().find_map(|turn| {
+            let next_dir = (dir + turn) % 4;
+            step(cell, next_dir).map(|next_cell| (next_cell, next_dir))
+        })
+    };
+
+    // The walk is back at the beginning once a `(cell, direction)` state repeats. `start_dir` is only
+    // a guess, so the walk may never arrive at `start` facing `start_dir` again.
+    let mut boundary = vec![start];
+    let mut visited_states = HashSet::new();
+    // `None` if it's a single-cell region
+    let mut state = next_move(start, start_dir);
+    while let Some(current_state) = state {
+        if !visited_states.insert(current_state) {
+            break;
+        }
+        boundary.push(current_state.0);
+        state = next_move(current_state.0, current_state.1);
+    }
+    // The last move is back to `start` before repeating the first move
+    if boundary.len() > 1 && boundary.last() == Some(&start) {
+        boundary.pop();
+    }
+    boundary
+}
+
 #[cfg(test)]
 mod tests {
     use std::fmt::Display;
@@ -48,7 +788,7 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, Clone, PartialEq)]
     enum Digit {
         Zero,
         One,
@@ -94,6 +834,36 @@ mod tests {
         assert!(grid.is_err());
     }
 
+    #[test]
+    fn test_parse_string_to_grid_with() {
+        let to_digit = |ch: char| match ch {
+            '0' => Some(Digit::Zero),
+            '1' => Some(Digit::One),
+            _ => None,
+        };
+
+        let grid = parse_string_to_grid_with("0011\n0101", to_digit);
+        assert_eq!(
+            grid.unwrap(),
+            grid![
+                [Digit::Zero, Digit::Zero, Digit::One, Digit::One]
+                [Digit::Zero, Digit::One, Digit::Zero, Digit::One]
+            ]
+        );
+
+        let grid = parse_string_to_grid_with("", to_digit);
+        assert_eq!(grid.unwrap(), Grid::default());
+
+        let grid = parse_string_to_grid_with("0011\n01x1", to_digit);
+        assert_eq!(
+            grid.unwrap_err().to_string(),
+            "Invalid character 'x' at line 2, column 3"
+        );
+
+        let grid = parse_string_to_grid_with("0011\n1", to_digit);
+        assert!(grid.is_err());
+    }
+
     #[test]
     fn test_grid_to_string() {
         let grid = grid![
@@ -101,5 +871,477 @@ mod tests {
             [Digit::Zero, Digit::One, Digit::Zero, Digit::One]
         ];
         assert_eq!(grid_to_string(&grid), String::from("0011\n0101"));
+
+        let grid = grid![['#', '.', '.']['.', '#', '.']['.', '.', '#']];
+        assert_eq!(grid_to_string(&grid), "#..\n.#.\n..#");
+
+        assert_eq!(grid_to_string(&Grid::<char>::new(0, 0)), "");
+    }
+
+    #[test]
+    fn test_grid_to_string_with_rulers() {
+        let grid = grid![
+            [Digit::Zero, Digit::Zero, Digit::One, Digit::One]
+            [Digit::Zero, Digit::One, Digit::Zero, Digit::One]
+        ];
+        assert_eq!(
+            grid_to_string_with_rulers(&grid),
+            String::from("  0123\n0 0011\n1 0101")
+        );
+
+        let grid = Grid::init(11, 12, '.');
+        let expected = r"
+             11
+   012345678901
+ 0 ............
+ 1 ............
+ 2 ............
+ 3 ............
+ 4 ............
+ 5 ............
+ 6 ............
+ 7 ............
+ 8 ............
+ 9 ............
+10 ............";
+        assert_eq!(grid_to_string_with_rulers(&grid), &expected[1..]);
+    }
+
+    #[test]
+    fn test_grid_symmetric_diff() {
+        let a = parse_string_to_grid("0011\n0101", char_to_digit).unwrap();
+        assert_eq!(grid_symmetric_diff(&a, &a.clone()), Some(vec![]));
+
+        let b = parse_string_to_grid("1011\n0110", char_to_digit).unwrap();
+        assert_eq!(
+            grid_symmetric_diff(&a, &b),
+            Some(vec![(0, 0), (1, 2), (1, 3)])
+        );
+        assert_eq!(
+            grid_symmetric_diff(&b, &a),
+            Some(vec![(0, 0), (1, 2), (1, 3)])
+        );
+
+        // Same cell count, but different dimensions
+        let c = parse_string_to_grid("00\n11\n01\n01", char_to_digit).unwrap();
+        assert_eq!(grid_symmetric_diff(&a, &c), None);
+        assert_eq!(grid_symmetric_diff(&a, &Grid::default()), None);
+    }
+
+    #[test]
+    fn test_replace_cells() {
+        let mut grid = parse_string_to_grid("0011\n0101", char_to_digit).unwrap();
+        assert_eq!(replace_cells(&mut grid, &Digit::Zero, &Digit::One), 4);
+        assert_eq!(grid_to_string(&grid), "1111\n1111");
+
+        assert_eq!(replace_cells(&mut grid, &Digit::Zero, &Digit::One), 0);
+        assert_eq!(grid_to_string(&grid), "1111\n1111");
+
+        assert_eq!(replace_cells(&mut grid, &Digit::One, &Digit::Zero), 8);
+        assert_eq!(grid_to_string(&grid), "0000\n0000");
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = grid![[1, 2, 3][4, 5, 6]];
+        let transposed = transpose(&grid);
+        assert_eq!(transposed.size(), (3, 2));
+        for ((row, col), cell) in grid.indexed_iter() {
+            assert_eq!(transposed[(col, row)], *cell);
+        }
+        assert_eq!(transpose(&transposed), grid);
+
+        let grid = parse_string_to_grid("001\n011\n111\n000", char_to_digit).unwrap();
+        assert_eq!(grid_to_string(&transpose(&grid)), "0010\n0110\n1110");
+
+        assert_eq!(transpose(&grid![[7]]), grid![[7]]);
+        assert_eq!(transpose(&Grid::<u8>::new(0, 0)), Grid::new(0, 0));
+    }
+
+    #[test]
+    fn test_rotated() {
+        let grid = parse_string_to_grid("001\n011\n111\n000", char_to_digit).unwrap();
+
+        let mut expected = grid.clone();
+        expected.rotate_right();
+        assert_eq!(rotated_right(&grid), expected);
+        assert_eq!(grid_to_string(&rotated_right(&grid)), "0100\n0110\n0111");
+
+        let mut expected = grid.clone();
+        expected.rotate_left();
+        assert_eq!(rotated_left(&grid), expected);
+        assert_eq!(grid_to_string(&rotated_left(&grid)), "1110\n0110\n0010");
+
+        // Original is preserved
+        assert_eq!(grid_to_string(&grid), "001\n011\n111\n000");
+        assert_eq!(rotated_left(&rotated_right(&grid)), grid);
+
+        let empty: Grid<u8> = Grid::new(0, 0);
+        assert_eq!(rotated_right(&empty), empty);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let input = "11111\n10010\n10110\n11111";
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        flood_fill(
+            &mut grid,
+            (1, 1),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+        );
+        // Pocket at the right side isn't connected to the start region
+        assert_eq!(grid_to_string(&grid), "11111\n11110\n11110\n11111");
+
+        // Start cell not fillable
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        flood_fill(
+            &mut grid,
+            (0, 0),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+        );
+        assert_eq!(grid_to_string(&grid), input);
+
+        // Region touching the grid edges stays in bounds
+        let mut grid = parse_string_to_grid("000\n010", char_to_digit).unwrap();
+        flood_fill(
+            &mut grid,
+            (0, 0),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+        );
+        assert_eq!(grid_to_string(&grid), "111\n111");
+    }
+
+    #[test]
+    fn test_flood_fill_with_limit() {
+        let input = "11111\n10001\n10101\n11111";
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let result = flood_fill_with_limit(
+            &mut grid,
+            (1, 1),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+            None,
+        );
+        assert_eq!(result, FloodFillResult::Completed(5));
+        assert_eq!(grid_to_string(&grid), "11111\n11111\n11111\n11111");
+
+        // Region has 5 cells, but only 2 are allowed
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let result = flood_fill_with_limit(
+            &mut grid,
+            (1, 1),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+            Some(2),
+        );
+        assert_eq!(result, FloodFillResult::Truncated(2));
+        assert_eq!(grid.iter().filter(|&cell| *cell == Digit::Zero).count(), 3);
+
+        // Limit equal to the region size still completes
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let result = flood_fill_with_limit(
+            &mut grid,
+            (1, 1),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+            Some(5),
+        );
+        assert_eq!(result, FloodFillResult::Completed(5));
+
+        // Start cell not fillable
+        let mut grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let result = flood_fill_with_limit(
+            &mut grid,
+            (0, 0),
+            |cell| *cell == Digit::Zero,
+            |cell| *cell = Digit::One,
+            None,
+        );
+        assert_eq!(result, FloodFillResult::Completed(0));
+    }
+
+    #[test]
+    fn test_bfs_shortest_path() {
+        let grid = parse_string_to_grid("0000\n1110\n0000\n0111\n0000", char_to_digit).unwrap();
+        let open_neighbors = |(row, col): (usize, usize)| {
+            [
+                (row.wrapping_sub(1), col),
+                (row + 1, col),
+                (row, col.wrapping_sub(1)),
+                (row, col + 1),
+            ]
+            .into_iter()
+            .filter(|&(r, c)| grid.get(r, c) == Some(&Digit::Zero))
+        };
+
+        // Path has to snake around the walls
+        assert_eq!(
+            bfs_shortest_path(&grid, (0, 0), (4, 3), open_neighbors),
+            Some(13)
+        );
+        assert_eq!(
+            bfs_shortest_path(&grid, (2, 1), (2, 1), open_neighbors),
+            Some(0)
+        );
+
+        // Goal is walled off
+        let grid = parse_string_to_grid("000\n011\n010", char_to_digit).unwrap();
+        let open_neighbors = |(row, col): (usize, usize)| {
+            [
+                (row.wrapping_sub(1), col),
+                (row + 1, col),
+                (row, col.wrapping_sub(1)),
+                (row, col + 1),
+            ]
+            .into_iter()
+            .filter(|&(r, c)| grid.get(r, c) == Some(&Digit::Zero))
+        };
+        assert_eq!(
+            bfs_shortest_path(&grid, (0, 0), (2, 2), open_neighbors),
+            None
+        );
+
+        // Start outside the grid
+        assert_eq!(
+            bfs_shortest_path(&grid, (5, 5), (0, 0), open_neighbors),
+            None
+        );
+    }
+
+    #[test]
+    fn test_connected_components() {
+        // 2 blobs of `1` surrounded by `0`
+        let input = "00000\n01100\n00000\n00011\n00001";
+        let grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let components = connected_components(&grid, |a, b| a == b);
+        assert_eq!(components.len(), 3);
+        assert_eq!(
+            components.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![20, 2, 3]
+        );
+        assert_eq!(components[1], vec![(1, 1), (1, 2)]);
+        assert_eq!(components[2], vec![(3, 3), (3, 4), (4, 4)]);
+
+        // A single blob of `1` touching the border, where diagonal cells are not connected
+        let input = "110\n100\n001";
+        let grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let components = connected_components(&grid, |a, b| a == b);
+        assert_eq!(
+            components,
+            vec![
+                vec![(0, 0), (0, 1), (1, 0)],
+                vec![(0, 2), (1, 1), (1, 2), (2, 0), (2, 1)],
+                vec![(2, 2)],
+            ]
+        );
+
+        // Everything belongs together
+        let components = connected_components(&grid, |_, _| true);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 9);
+
+        assert_eq!(
+            connected_components(&Grid::<u8>::new(0, 0), |a, b| a == b),
+            Vec::<Vec<(usize, usize)>>::new()
+        );
+    }
+
+    #[test]
+    fn test_label_regions() {
+        // 2 blobs of `1`, where diagonal cells are not connected
+        let input = "1100\n1001\n0011\n0000";
+        let grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let labels = label_regions(&grid, |cell| *cell == Digit::One);
+        assert_eq!(
+            labels,
+            grid![
+                [Some(0), Some(0), None, None]
+                [Some(0), None, None, Some(1)]
+                [None, None, Some(1), Some(1)]
+                [None, None, None, None]
+            ]
+        );
+
+        // All `0` are connected
+        let labels = label_regions(&grid, |cell| *cell == Digit::Zero);
+        assert!(
+            labels
+                .indexed_iter()
+                .all(|(coords, id)| id.is_some() == (grid[coords] == Digit::Zero))
+        );
+        assert!(labels.iter().flatten().all(|&id| id == 0));
+
+        assert_eq!(
+            label_regions(&Grid::<u8>::new(0, 0), |_| true),
+            Grid::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_trace_boundary() {
+        let input = r"
+000000
+011110
+011110
+011110
+000000
+"
+        .trim();
+        let grid = parse_string_to_grid(input, char_to_digit).unwrap();
+        let is_wall = |cell: &Digit| *cell == Digit::Zero;
+
+        let boundary = trace_boundary(&grid, (1, 1), is_wall);
+        assert_eq!(boundary.len(), 2 * (3 + 4) - 4);
+        assert_eq!(
+            boundary,
+            vec![
+                (1, 1),
+                (2, 1),
+                (3, 1),
+                (3, 2),
+                (3, 3),
+                (3, 4),
+                (2, 4),
+                (1, 4),
+                (1, 3),
+                (1, 2)
+            ]
+        );
+
+        // Starting elsewhere gives the same cycle
+        let boundary_from_side = trace_boundary(&grid, (3, 2), is_wall);
+        assert_eq!(boundary_from_side.len(), boundary.len());
+        assert_eq!(&boundary_from_side[..2], &[(3, 2), (3, 3)]);
+
+        // Not on the boundary, or is a wall
+        assert_eq!(trace_boundary(&grid, (2, 2), is_wall), vec![]);
+        assert_eq!(trace_boundary(&grid, (0, 0), is_wall), vec![]);
+
+        // Grid border acts as wall
+        let grid = parse_string_to_grid("11\n11", char_to_digit).unwrap();
+        assert_eq!(
+            trace_boundary(&grid, (0, 0), is_wall),
+            vec![(0, 0), (1, 0), (1, 1), (0, 1)]
+        );
+        // Starting at the top right corner, the walk never arrives at the start facing west
+        assert_eq!(
+            trace_boundary(&grid, (0, 1), is_wall),
+            vec![(0, 1), (0, 0), (1, 0), (1, 1)]
+        );
+
+        // Single cell and 1-cell-wide region
+        let grid = parse_string_to_grid("010\n000", char_to_digit).unwrap();
+        assert_eq!(trace_boundary(&grid, (0, 1), is_wall), vec![(0, 1)]);
+        let grid = parse_string_to_grid("111", char_to_digit).unwrap();
+        assert_eq!(
+            trace_boundary(&grid, (0, 0), is_wall),
+            vec![(0, 0), (0, 1), (0, 2), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_render_coords() {
+        let coords = [
+            Coords2D::new(1, 1),
+            Coords2D::new(4, 1),
+            Coords2D::new(4, 3),
+            Coords2D::new(2, 4),
+        ];
+        let top_left = render_coords(&coords, Origin::TopLeft);
+        assert_eq!(top_left, "#..#\n....\n...#\n.#..");
+
+        let bottom_left = render_coords(&coords, Origin::BottomLeft);
+        assert_eq!(bottom_left, ".#..\n...#\n....\n#..#");
+        assert_eq!(
+            bottom_left,
+            top_left.lines().rev().collect::<Vec<_>>().join("\n")
+        );
+
+        let coords = [Coords2D::new(-3, -5)];
+        assert_eq!(render_coords(&coords, Origin::TopLeft), "#");
+        assert_eq!(render_coords(&[], Origin::TopLeft), "");
+    }
+
+    #[test]
+    fn test_grid_row_masks() {
+        let grid = parse_string_to_grid("0011\n0101\n0000", char_to_digit).unwrap();
+        let masks = grid_row_masks(&grid, |cell| *cell == Digit::One);
+        assert_eq!(masks, vec![vec![0b1100], vec![0b1010], vec![0]]);
+
+        // 130 columns span 2 words
+        let grid = Grid::from_vec((0..130).map(|col| col % 2 == 0).collect(), 130);
+        let masks = grid_row_masks(&grid, |&cell| cell);
+        assert_eq!(masks.len(), 1);
+        assert_eq!(masks[0].len(), 2);
+        assert_eq!(masks[0][1], 0b01);
+
+        assert_eq!(
+            grid_row_masks(&Grid::<bool>::new(0, 0), |&cell| cell),
+            Vec::<Vec<u128>>::new()
+        );
+    }
+
+    #[test]
+    fn test_span_all_set() {
+        let grid = parse_string_to_grid("0111\n1011\n1111", char_to_digit).unwrap();
+        let masks = grid_row_masks(&grid, |cell| *cell == Digit::One);
+
+        assert!(span_all_set(&masks, 0, 1..4));
+        assert!(!span_all_set(&masks, 0, 0..4));
+        assert!(span_all_set(&masks, 1, 2..4));
+        assert!(!span_all_set(&masks, 1, 0..3));
+        assert!(span_all_set(&masks, 2, 0..4));
+        assert!(span_all_set(&masks, 0, 2..2));
+
+        // Outside the grid
+        assert!(!span_all_set(&masks, 2, 0..5));
+        assert!(!span_all_set(&masks, 3, 0..1));
+
+        // Spans crossing the word boundary
+        let mut grid = Grid::init(1, 300, true);
+        grid[(0, 200)] = false;
+        let masks = grid_row_masks(&grid, |&cell| cell);
+        assert!(span_all_set(&masks, 0, 0..200));
+        assert!(span_all_set(&masks, 0, 100..200));
+        assert!(span_all_set(&masks, 0, 201..300));
+        assert!(span_all_set(&masks, 0, 0..128));
+        assert!(span_all_set(&masks, 0, 128..129));
+        assert!(!span_all_set(&masks, 0, 100..201));
+        assert!(!span_all_set(&masks, 0, 0..300));
+    }
+
+    #[test]
+    fn test_all_filled_rectangles() {
+        let to_bool_grid = |input: &str| parse_string_to_grid(input, |ch| Ok(ch == '#')).unwrap();
+
+        // Two separate rectangles
+        let grid = to_bool_grid("##..\n##..\n...#\n...#");
+        assert_eq!(
+            all_filled_rectangles(&grid, |&cell| cell),
+            vec![((0, 0), (1, 1), 4), ((2, 3), (3, 3), 2)]
+        );
+
+        // Overlapping maximal rectangles
+        let grid = to_bool_grid(".##.\n####\n.##.");
+        assert_eq!(
+            all_filled_rectangles(&grid, |&cell| cell),
+            vec![((0, 1), (2, 2), 6), ((1, 0), (1, 3), 4)]
+        );
+
+        let grid = to_bool_grid("###\n###");
+        assert_eq!(
+            all_filled_rectangles(&grid, |&cell| cell),
+            vec![((0, 0), (1, 2), 6)]
+        );
+
+        let grid = to_bool_grid("...\n...");
+        assert_eq!(all_filled_rectangles(&grid, |&cell| cell), vec![]);
+        assert_eq!(
+            all_filled_rectangles(&Grid::<bool>::new(0, 0), |&cell| cell),
+            vec![]
+        );
     }
 }
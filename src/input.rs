@@ -0,0 +1,50 @@
+//! Utilities for reading puzzle inputs.
+
+use std::fs;
+
+use anyhow::{Context, Result};
+
+/// Reads the whole file at `path` to a string. Unlike [fs::read_to_string], the error names the
+/// path, so a missing puzzle input is easy to spot.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::input::read_input;
+///
+/// let error = read_input("puzzle_inputs/day00.txt").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "failed to read puzzle input at puzzle_inputs/day00.txt"
+/// );
+/// ```
+pub fn read_input(path: &str) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read puzzle input at {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_input() {
+        let path = std::env::temp_dir().join(format!("aoc-read-input-{}.txt", std::process::id()));
+        fs::write(&path, "3-5\n10-14\n").unwrap();
+
+        let input = read_input(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(input.unwrap(), "3-5\n10-14\n");
+    }
+
+    #[test]
+    fn test_read_input_missing_file() {
+        let path = std::env::temp_dir().join("aoc-read-input-missing.txt");
+        let path = path.to_str().unwrap();
+
+        let error = read_input(path).unwrap_err();
+        assert!(error.to_string().contains(path));
+        // The underlying I/O error is kept as the cause
+        assert!(error.chain().count() > 1);
+    }
+}
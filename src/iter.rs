@@ -0,0 +1,137 @@
+//! Utilities for working with iterators.
+
+/// Returns the item with the largest key computed by `f`, or `None` if `items` is empty.
+///
+/// If several items share the largest key, the last one is returned (same as [Iterator::max_by_key]).
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::iter::max_by_key_or;
+///
+/// assert_eq!(max_by_key_or(["a", "abc", "ab"].into_iter(), |s| s.len()), Some("abc"));
+/// assert_eq!(max_by_key_or(Vec::<&str>::new().into_iter(), |s| s.len()), None);
+/// ```
+pub fn max_by_key_or<T, K: Ord, F: Fn(&T) -> K>(items: impl Iterator<Item = T>, f: F) -> Option<T> {
+    items.max_by_key(f)
+}
+
+/// Iterates over every unordered pair of items `(items[i], items[j])` where `i < j`.
+///
+/// Equivalent to `items.iter().combinations(2)` from [itertools], but doesn't allocate a [Vec]
+/// for each pair.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::iter::unordered_pairs;
+///
+/// let pairs: Vec<(&i32, &i32)> = unordered_pairs(&[1, 2, 3]).collect();
+/// assert_eq!(pairs, vec![(&1, &2), (&1, &3), (&2, &3)]);
+/// ```
+pub fn unordered_pairs<T>(items: &[T]) -> impl Iterator<Item = (&T, &T)> {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| (a, b)))
+}
+
+/// Keeps applying `step` to the state until it stops changing (i.e. `step(state) == state`), or
+/// `max_iters` steps have been applied. Pass `None` to `max_iters` to loop until the fixpoint.
+///
+/// Returns the final state and the number of steps that changed the state.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::iter::iterate_to_fixpoint;
+///
+/// assert_eq!(iterate_to_fixpoint(20, |&n| n / 2, None), (0, 5)); // 20 -> 10 -> 5 -> 2 -> 1 -> 0
+/// assert_eq!(iterate_to_fixpoint(20, |&n| n / 2, Some(2)), (5, 2));
+/// ```
+pub fn iterate_to_fixpoint<T: Clone + PartialEq, F: Fn(&T) -> T>(
+    initial: T,
+    step: F,
+    max_iters: Option<usize>,
+) -> (T, usize) {
+    let mut state = initial;
+    let mut iters = 0;
+    while max_iters.is_none_or(|max| iters < max) {
+        let next = step(&state);
+        if next == state {
+            break;
+        }
+        state = next;
+        iters += 1;
+    }
+    (state, iters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_max_by_key_or() {
+        assert_eq!(max_by_key_or(std::iter::empty::<i32>(), |&n| n), None);
+        assert_eq!(max_by_key_or([3].into_iter(), |&n| n), Some(3));
+        assert_eq!(max_by_key_or([3, -7, 5, 1].into_iter(), |&n| n), Some(5));
+        assert_eq!(
+            max_by_key_or([3_i32, -7, 5, 1].into_iter(), |&n| n.abs()),
+            Some(-7)
+        );
+    }
+
+    #[test]
+    fn test_unordered_pairs() {
+        assert_eq!(unordered_pairs::<i32>(&[]).count(), 0);
+        assert_eq!(unordered_pairs(&[1]).count(), 0);
+
+        for n in 2..10 {
+            let items: Vec<usize> = (0..n).collect();
+            assert_eq!(unordered_pairs(&items).count(), n * (n - 1) / 2);
+
+            let expected: Vec<(&usize, &usize)> = items
+                .iter()
+                .combinations(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect();
+            assert_eq!(unordered_pairs(&items).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_iterate_to_fixpoint() {
+        // Already at the fixpoint
+        assert_eq!(iterate_to_fixpoint(0, |&n| n / 2, None), (0, 0));
+
+        // Converges to the fixpoint of `n -> (n + 9 / n) / 2`, i.e. integer square root of 9
+        assert_eq!(
+            iterate_to_fixpoint(9_i32, |&n| (n + 9 / n) / 2, None),
+            (3, 2)
+        );
+
+        let remove_last = |items: &Vec<i32>| items[..items.len().saturating_sub(1)].to_vec();
+        assert_eq!(
+            iterate_to_fixpoint(vec![1, 2, 3], remove_last, None),
+            (vec![], 3)
+        );
+
+        // Capped by `max_iters`
+        assert_eq!(
+            iterate_to_fixpoint(vec![1, 2, 3], remove_last, Some(2)),
+            (vec![1], 2)
+        );
+        assert_eq!(
+            iterate_to_fixpoint(vec![1, 2, 3], remove_last, Some(0)),
+            (vec![1, 2, 3], 0)
+        );
+        // Never converges
+        assert_eq!(
+            iterate_to_fixpoint(0_u64, |&n| n + 1, Some(100)),
+            (100, 100)
+        );
+    }
+}
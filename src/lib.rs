@@ -1,7 +1,23 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+pub mod bitset;
+pub mod cache;
 pub mod coords;
+pub mod digits;
+pub mod direction;
+pub mod graph;
 pub mod grid;
+pub mod input;
+pub mod iter;
 pub mod line;
 pub mod parse;
+pub mod prelude;
+pub mod test_support;
+pub mod union_find;
+
+pub use direction::Direction;
 
 /// Part One/Two of the problem.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -9,3 +25,87 @@ pub enum Part {
     One,
     Two,
 }
+
+impl Part {
+    /// Iterates over all parts in order, i.e. [Part::One] then [Part::Two].
+    ///
+    /// ```
+    /// use advent_of_code_2025::Part;
+    ///
+    /// assert_eq!(Part::iter().collect::<Vec<_>>(), vec![Part::One, Part::Two]);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Part> {
+        [Part::One, Part::Two].into_iter()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// Error when parsing a [Part] from a string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid part '{0}', expected one of '1', '2', 'one' or 'two'")]
+pub struct ParsePartError(String);
+
+/// Parses `"1"`/`"one"` to [Part::One] and `"2"`/`"two"` to [Part::Two], case-insensitively.
+///
+/// ```
+/// use advent_of_code_2025::Part;
+///
+/// assert_eq!("Two".parse(), Ok(Part::Two));
+/// ```
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(ParsePartError(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_part_iter() {
+        assert_eq!(Part::iter().collect::<Vec<_>>(), vec![Part::One, Part::Two]);
+    }
+
+    #[test]
+    fn test_part_display() {
+        assert_eq!(Part::One.to_string(), "Part 1");
+        assert_eq!(Part::Two.to_string(), "Part 2");
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        for input in ["1", "one", "One", "ONE"] {
+            assert_eq!(input.parse(), Ok(Part::One));
+        }
+        for input in ["2", "two", "Two", "TWO"] {
+            assert_eq!(input.parse(), Ok(Part::Two));
+        }
+
+        for input in ["", "3", "three", " 1", "part 1"] {
+            assert_eq!(
+                input.parse::<Part>(),
+                Err(ParsePartError(input.to_string()))
+            );
+        }
+        assert_eq!(
+            "0".parse::<Part>().unwrap_err().to_string(),
+            "invalid part '0', expected one of '1', '2', 'one' or 'two'"
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use std::hash::Hash;
+use std::{hash::Hash, str::FromStr};
 
-use crate::coords::Coords3D;
+use thiserror::Error;
+
+use crate::coords::{Coords2D, Coords3D, ParseCoordsError};
 
 /// Represents an undirected line connecting two [Coords3D].
 ///
@@ -31,6 +33,212 @@ impl Line3D {
     pub fn len(&self) -> f64 {
         self.0.distance(&self.1)
     }
+
+    /// Squared length of the line, i.e. sum of squared axis deltas without the square root.
+    ///
+    /// Prefer this over [Self::len] for sorting and comparing lines, since it's exact integer
+    /// arithmetic and orders lines the same way as [Self::len].
+    ///
+    /// ```
+    /// use advent_of_code_2025::line::Line3D;
+    ///
+    /// assert_eq!(Line3D::new((0, 0, 0), (2, 3, 6)).len_squared(), 49);
+    /// ```
+    #[inline]
+    pub fn len_squared(&self) -> i64 {
+        (self.0.x - self.1.x).pow(2) + (self.0.y - self.1.y).pow(2) + (self.0.z - self.1.z).pow(2)
+    }
+
+    /// Integer midpoint of the line. Each axis is halved with integer division, so an odd sum rounds
+    /// toward zero.
+    ///
+    /// ```
+    /// use advent_of_code_2025::{coords::Coords3D, line::Line3D};
+    ///
+    /// assert_eq!(Line3D::new((0, 0, 0), (4, -6, 3)).midpoint(), Coords3D::new(2, -3, 1));
+    /// assert_eq!(Line3D::new((0, 0, 0), (-3, 0, 0)).midpoint(), Coords3D::new(-1, 0, 0));
+    /// ```
+    pub fn midpoint(&self) -> Coords3D {
+        Coords3D::new(
+            (self.0.x + self.1.x) / 2,
+            (self.0.y + self.1.y) / 2,
+            (self.0.z + self.1.z) / 2,
+        )
+    }
+
+    /// Reduced integer direction vector from the first endpoint to the second, i.e. the delta divided
+    /// by the GCD of its axes. A zero-length line has direction `(0, 0, 0)`.
+    ///
+    /// ```
+    /// use advent_of_code_2025::{coords::Coords3D, line::Line3D};
+    ///
+    /// assert_eq!(Line3D::new((0, 0, 0), (4, -6, 2)).direction(), Coords3D::new(2, -3, 1));
+    /// assert_eq!(Line3D::new((0, 0, 0), (0, 0, 6)).direction(), Coords3D::new(0, 0, 1));
+    /// ```
+    pub fn direction(&self) -> Coords3D {
+        let (dx, dy, dz) = (
+            self.1.x - self.0.x,
+            self.1.y - self.0.y,
+            self.1.z - self.0.z,
+        );
+        let divisor = gcd(gcd(dx, dy), dz);
+        if divisor == 0 {
+            return Coords3D::new(0, 0, 0);
+        }
+        Coords3D::new(dx / divisor, dy / divisor, dz / divisor)
+    }
+
+    /// Iterates over the integer points on the line, from the first endpoint to the second (both
+    /// inclusive), stepping by [Self::direction].
+    ///
+    /// Only axis-aligned lines and clean diagonals, i.e. lines whose axis deltas share a common step,
+    /// have points in between. Other lines yield just the two endpoints, and a zero-length line yields
+    /// its single point once.
+    ///
+    /// ```
+    /// use advent_of_code_2025::{coords::Coords3D, line::Line3D};
+    ///
+    /// let points: Vec<Coords3D> = Line3D::new((0, 0, 0), (4, -6, 2)).points().collect();
+    /// assert_eq!(points, vec![Coords3D::new(0, 0, 0), Coords3D::new(2, -3, 1), Coords3D::new(4, -6, 2)]);
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = Coords3D> {
+        let start = self.0;
+        let step = self.direction();
+        let steps = if step.x != 0 {
+            (self.1.x - start.x) / step.x
+        } else if step.y != 0 {
+            (self.1.y - start.y) / step.y
+        } else if step.z != 0 {
+            (self.1.z - start.z) / step.z
+        } else {
+            0
+        };
+        (0..=steps).map(move |i| {
+            Coords3D::new(
+                start.x + i * step.x,
+                start.y + i * step.y,
+                start.z + i * step.z,
+            )
+        })
+    }
+
+    /// Returns the two endpoints in ascending order, so that `Line3D(A, B)` and `Line3D(B, A)` have
+    /// the same canonical form. Useful as a deterministic sort key.
+    ///
+    /// ```
+    /// use advent_of_code_2025::{coords::Coords3D, line::Line3D};
+    ///
+    /// let (a, b) = (Coords3D::new(4, 5, 6), Coords3D::new(1, 2, 3));
+    /// assert_eq!(Line3D(a, b).canonical(), (b, a));
+    /// assert_eq!(Line3D(b, a).canonical(), (b, a));
+    /// ```
+    pub fn canonical(&self) -> (Coords3D, Coords3D) {
+        if self.0 <= self.1 {
+            (self.0, self.1)
+        } else {
+            (self.1, self.0)
+        }
+    }
+}
+
+/// Represents an undirected line connecting two [Coords2D].
+///
+/// # Undirected
+///
+/// Same as [Line3D], two [Line2D] with same pair of [Coords2D] but different order are considered
+/// equal.
+///
+/// ```
+/// use advent_of_code_2025::line::Line2D;
+///
+/// assert!(Line2D::new((1, 2), (3, 4)) == Line2D::new((3, 4), (1, 2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Line2D(pub Coords2D, pub Coords2D);
+
+impl Line2D {
+    #[must_use]
+    #[inline]
+    pub fn new(first_coord: (i64, i64), second_coord: (i64, i64)) -> Self {
+        Line2D(
+            Coords2D::new(first_coord.0, first_coord.1),
+            Coords2D::new(second_coord.0, second_coord.1),
+        )
+    }
+
+    #[inline]
+    pub fn len(&self) -> f64 {
+        self.0.distance(&self.1)
+    }
+
+    /// Returns the two endpoints in ascending order, so that `Line2D(A, B)` and `Line2D(B, A)` have
+    /// the same canonical form.
+    pub fn canonical(&self) -> (&Coords2D, &Coords2D) {
+        if self.0 <= self.1 {
+            (&self.0, &self.1)
+        } else {
+            (&self.1, &self.0)
+        }
+    }
+}
+
+// Ensures `Line2D(A, B) == Line2D(B, A)``
+impl PartialEq for Line2D {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1 || self.0 == other.1 && self.1 == other.0
+    }
+}
+
+impl Eq for Line2D {}
+
+impl Hash for Line2D {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Always hash in a consistent order so that `hash(Line2D(A, B)) == hash(Line2D(B, A))`
+        let (min, max) = self.canonical();
+        min.hash(state);
+        max.hash(state);
+    }
+}
+
+/// [Greatest common divisor](https://en.wikipedia.org/wiki/Greatest_common_divisor) of the absolute
+/// values, where `gcd(0, 0) = 0`.
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Error when parsing a [Line3D] from a string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseLineError {
+    #[error("expected 2 space-separated endpoints in '{input}', but found {found}")]
+    WrongEndpointCount { input: String, found: usize },
+    #[error(transparent)]
+    InvalidCoords(#[from] ParseCoordsError),
+}
+
+/// Parses two space-separated coordinates like `"1,2,3 4,5,6"` to a [Line3D].
+///
+/// ```
+/// use advent_of_code_2025::line::Line3D;
+///
+/// assert_eq!("1,2,3 4,5,6".parse(), Ok(Line3D::new((1, 2, 3), (4, 5, 6))));
+/// ```
+impl FromStr for Line3D {
+    type Err = ParseLineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let endpoints: Vec<&str> = s.split_whitespace().collect();
+        match endpoints[..] {
+            [first, second] => Ok(Line3D(first.parse()?, second.parse()?)),
+            _ => Err(ParseLineError::WrongEndpointCount {
+                input: s.into(),
+                found: endpoints.len(),
+            }),
+        }
+    }
 }
 
 // Ensures `Line3D(A, B) == Line3D(B, A)``
@@ -45,14 +253,7 @@ impl Eq for Line3D {}
 impl Hash for Line3D {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         // Always hash in a consistent order so that `hash(Line3D(A, B)) == hash(Line3D(B, A))`
-        let (min, max) = if self.0.x < self.1.x
-            || (self.0.x == self.1.x && self.0.y < self.1.y)
-            || (self.0.x == self.1.x && self.0.y == self.1.y && self.0.z < self.1.z)
-        {
-            (self.0, self.1)
-        } else {
-            (self.1, self.0)
-        };
+        let (min, max) = self.canonical();
         min.hash(state);
         max.hash(state);
     }
@@ -75,6 +276,140 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_len_squared() {
+        let lines = [
+            Line3D::new((0, 0, 0), (0, 0, 0)),
+            Line3D::new((0, 0, 0), (1, 0, 0)),
+            Line3D::new((0, 0, 0), (1, 1, 0)),
+            Line3D::new((0, 0, 0), (1, 1, 1)),
+            Line3D::new((0, 0, 0), (2, 0, 0)),
+            Line3D::new((-1, -2, -3), (2, 2, 1)),
+            Line3D::new((2, 2, 1), (-1, -2, -3)),
+            Line3D::new((162, 817, 812), (425, 690, 689)),
+        ];
+        assert_eq!(
+            lines.iter().map(Line3D::len_squared).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 41, 41, 100427]
+        );
+        for line in &lines {
+            assert_eq!(line.len_squared(), line.len().powi(2).round() as i64);
+        }
+
+        // Ordering by `len_squared` is the same as ordering by `len`
+        for (a, b) in itertools::iproduct!(&lines, &lines) {
+            assert_eq!(
+                a.len_squared().cmp(&b.len_squared()),
+                a.len().partial_cmp(&b.len()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_midpoint() {
+        // Diagonal
+        assert_eq!(
+            Line3D::new((1, 2, 3), (5, 6, 7)).midpoint(),
+            Coords3D::new(3, 4, 5)
+        );
+        assert_eq!(
+            Line3D::new((5, 6, 7), (1, 2, 3)).midpoint(),
+            Coords3D::new(3, 4, 5)
+        );
+        // Odd sums round toward zero
+        assert_eq!(
+            Line3D::new((0, 0, 0), (3, -3, 1)).midpoint(),
+            Coords3D::new(1, -1, 0)
+        );
+        // Axis-aligned
+        assert_eq!(
+            Line3D::new((0, 0, 0), (0, 0, 6)).midpoint(),
+            Coords3D::new(0, 0, 3)
+        );
+        // Zero-length
+        assert_eq!(
+            Line3D::new((-4, 2, 9), (-4, 2, 9)).midpoint(),
+            Coords3D::new(-4, 2, 9)
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        // Diagonal
+        assert_eq!(
+            Line3D::new((1, 1, 1), (4, 4, 4)).direction(),
+            Coords3D::new(1, 1, 1)
+        );
+        assert_eq!(
+            Line3D::new((4, 4, 4), (1, 1, 1)).direction(),
+            Coords3D::new(-1, -1, -1)
+        );
+        assert_eq!(
+            Line3D::new((0, 0, 0), (6, -9, 12)).direction(),
+            Coords3D::new(2, -3, 4)
+        );
+        assert_eq!(
+            Line3D::new((0, 0, 0), (2, 3, 5)).direction(),
+            Coords3D::new(2, 3, 5)
+        );
+        // Axis-aligned
+        assert_eq!(
+            Line3D::new((0, 0, 0), (0, 0, 6)).direction(),
+            Coords3D::new(0, 0, 1)
+        );
+        assert_eq!(
+            Line3D::new((3, 5, 0), (-7, 5, 0)).direction(),
+            Coords3D::new(-1, 0, 0)
+        );
+        // Zero-length
+        assert_eq!(
+            Line3D::new((-4, 2, 9), (-4, 2, 9)).direction(),
+            Coords3D::new(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_points() {
+        // Vertical
+        assert_eq!(
+            Line3D::new((2, 3, 1), (2, 0, 1))
+                .points()
+                .collect::<Vec<_>>(),
+            vec![
+                Coords3D::new(2, 3, 1),
+                Coords3D::new(2, 2, 1),
+                Coords3D::new(2, 1, 1),
+                Coords3D::new(2, 0, 1),
+            ]
+        );
+        // Diagonal
+        assert_eq!(
+            Line3D::new((0, 0, 0), (3, 3, 3))
+                .points()
+                .collect::<Vec<_>>(),
+            vec![
+                Coords3D::new(0, 0, 0),
+                Coords3D::new(1, 1, 1),
+                Coords3D::new(2, 2, 2),
+                Coords3D::new(3, 3, 3),
+            ]
+        );
+        // Not lattice-aligned
+        assert_eq!(
+            Line3D::new((0, 0, 0), (2, 3, 5))
+                .points()
+                .collect::<Vec<_>>(),
+            vec![Coords3D::new(0, 0, 0), Coords3D::new(2, 3, 5)]
+        );
+        // Zero-length
+        assert_eq!(
+            Line3D::new((-4, 2, 9), (-4, 2, 9))
+                .points()
+                .collect::<Vec<_>>(),
+            vec![Coords3D::new(-4, 2, 9)]
+        );
+    }
+
     #[test]
     fn test_equality() {
         assert!(Line3D::new((1, 2, 3), (4, 5, 6)) == Line3D::new((1, 2, 3), (4, 5, 6)));
@@ -82,6 +417,50 @@ mod tests {
         assert!(Line3D::new((1, 2, 3), (4, 5, 6)) != Line3D::new((0, 2, 3), (4, 5, 6)));
     }
 
+    #[test]
+    fn test_canonical() {
+        let (a, b) = (Coords3D::new(1, 2, 3), Coords3D::new(1, 2, 4));
+        assert_eq!(Line3D(a, b).canonical(), (a, b));
+        assert_eq!(Line3D(b, a).canonical(), (a, b));
+        assert_eq!(Line3D(a, a).canonical(), (a, a));
+
+        let (a, b) = (Coords3D::new(0, 9, 9), Coords3D::new(1, 0, 0));
+        assert_eq!(Line3D(b, a).canonical(), (a, b));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1,2,3 4,5,6".parse(), Ok(Line3D::new((1, 2, 3), (4, 5, 6))));
+        assert_eq!(
+            "  -1,-2,-3   4,5,6 ".parse(),
+            Ok(Line3D::new((-1, -2, -3), (4, 5, 6)))
+        );
+
+        assert_eq!(
+            "1,2,3".parse::<Line3D>(),
+            Err(ParseLineError::WrongEndpointCount {
+                input: "1,2,3".into(),
+                found: 1
+            })
+        );
+        assert!(matches!(
+            "1,2,3 4,5,6 7,8,9".parse::<Line3D>(),
+            Err(ParseLineError::WrongEndpointCount { found: 3, .. })
+        ));
+        assert!(matches!(
+            "1,2,3 4,5".parse::<Line3D>(),
+            Err(ParseLineError::InvalidCoords(
+                ParseCoordsError::WrongValueCount { .. }
+            ))
+        ));
+        assert!(matches!(
+            "1,2,x 4,5,6".parse::<Line3D>(),
+            Err(ParseLineError::InvalidCoords(
+                ParseCoordsError::InvalidInteger { .. }
+            ))
+        ));
+    }
+
     #[test]
     fn test_hash() {
         use std::collections::hash_map::DefaultHasher;
@@ -117,4 +496,59 @@ mod tests {
         assert!(set.contains(&Line3D::new((4, 5, 6), (1, 2, 3))));
         assert_eq!(set.len(), 1);
     }
+
+    #[test]
+    fn test_line_2d_length() {
+        assert_eq!(Line2D::new((-1, -2), (2, 2)).len(), 5_f64);
+        assert_eq!(
+            Line2D::new((-1, -2), (2, 2)).len(),
+            Line2D::new((2, 2), (-1, -2)).len(),
+        );
+        assert_eq!(Line2D::new((3, 3), (3, 3)).len(), 0_f64);
+    }
+
+    #[test]
+    fn test_line_2d_equality() {
+        assert!(Line2D::new((1, 2), (3, 4)) == Line2D::new((1, 2), (3, 4)));
+        assert!(Line2D::new((1, 2), (3, 4)) == Line2D::new((3, 4), (1, 2)));
+        assert!(Line2D::new((1, 2), (3, 4)) != Line2D::new((0, 2), (3, 4)));
+        assert!(Line2D::new((1, 2), (3, 4)) != Line2D::new((2, 1), (4, 3)));
+    }
+
+    #[test]
+    fn test_line_2d_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+
+        // Line2D(A, B) should have same hash as Line2D(B, A)
+        Line2D::new((1, 2), (3, 4)).hash(&mut hasher1);
+        Line2D::new((3, 4), (1, 2)).hash(&mut hasher2);
+        assert_eq!(
+            hasher1.finish(),
+            hasher2.finish(),
+            "Lines with swapped endpoints should have the same hash"
+        );
+
+        // Different lines should (likely) have different hashes
+        let mut hasher3 = DefaultHasher::new();
+        Line2D::new((0, 0), (1, 1)).hash(&mut hasher3);
+        assert_ne!(
+            hasher1.finish(),
+            hasher3.finish(),
+            "Different lines should have different hashes"
+        );
+
+        use std::collections::HashSet;
+        let set: HashSet<Line2D> = HashSet::from([
+            Line2D::new((1, 2), (3, 4)),
+            Line2D::new((3, 4), (1, 2)),
+            Line2D::new((0, 0), (1, 1)),
+        ]);
+        assert!(set.contains(&Line2D::new((1, 2), (3, 4))));
+        assert!(set.contains(&Line2D::new((1, 1), (0, 0))));
+        assert_eq!(set.len(), 2);
+    }
 }
@@ -2,6 +2,9 @@
 
 use std::ops::RangeInclusive;
 
+use anyhow::{Context, Result};
+use grid::Grid;
+
 /// Parses a number range string like `5-10` into a [RangeInclusive] range.
 ///
 /// # Panic
@@ -22,6 +25,168 @@ pub fn parse_u64_number_range(input: &str) -> RangeInclusive<u64> {
     start.parse().unwrap()..=end.parse().unwrap()
 }
 
+/// Parses a signed number range string like `-10--2` into a [RangeInclusive] range.
+///
+/// The delimiter is the first `-` after the first character, since a leading `-` is the sign of the
+/// start. A `-` right after the delimiter is the sign of the end. Whitespaces around each number are
+/// ignored.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::parse::parse_i64_number_range;
+///
+/// assert_eq!(parse_i64_number_range("-3-4").unwrap(), -3..=4);
+/// assert_eq!(parse_i64_number_range("-10--2").unwrap(), -10..=-2);
+/// assert!(parse_i64_number_range("5").is_err());
+/// ```
+pub fn parse_i64_number_range(input: &str) -> Result<RangeInclusive<i64>> {
+    let input = input.trim();
+    let delimiter_idx = input
+        .char_indices()
+        .skip(1)
+        .find_map(|(idx, ch)| (ch == '-').then_some(idx))
+        .with_context(|| format!("Range '{input}' should be delimited by `-`"))?;
+    let (start, end) = (&input[..delimiter_idx], &input[delimiter_idx + 1..]);
+
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<i64>()
+            .with_context(|| format!("Invalid number '{value}' in range '{input}'"))
+    };
+    Ok(parse(start)?..=parse(end)?)
+}
+
+/// Parses lines of whitespace-separated integers into a [Grid], where each line is a row.
+///
+/// Like [parse_string_to_grid](crate::grid::parse_string_to_grid), every row should have the same
+/// number of columns. An empty input gives an empty grid.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::parse::parse_number_grid;
+/// use grid::*;
+///
+/// let grid = parse_number_grid("123 -45\n  6   78").unwrap();
+/// assert_eq!(grid, grid![[123, -45][6, 78]]);
+/// assert!(parse_number_grid("1 2\n3").is_err());
+/// ```
+pub fn parse_number_grid(input: &str) -> Result<Grid<i64>> {
+    let rows: Vec<Vec<i64>> = input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            line.split_whitespace()
+                .map(|token| {
+                    token.parse::<i64>().with_context(|| {
+                        format!("Invalid number '{token}' at line {}", line_idx + 1)
+                    })
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+
+    let Some(width) = rows.first().map(|row| row.len()) else {
+        return Ok(Grid::new(0, 0));
+    };
+    if let Some(line_idx) = rows.iter().position(|row| row.len() != width) {
+        return Err(anyhow::anyhow!(
+            "Expected {width} numbers in each line, but found {} at line {}",
+            rows[line_idx].len(),
+            line_idx + 1
+        ));
+    }
+
+    Ok(Grid::from_vec(rows.into_iter().flatten().collect(), width))
+}
+
+/// Gets the dimensions of the input as `(line_count, max_line_width)` without parsing it. The width
+/// is counted in characters.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::parse::input_dimensions;
+///
+/// assert_eq!(input_dimensions("..S..\n.^.\n"), (2, 5));
+/// ```
+pub fn input_dimensions(input: &str) -> (usize, usize) {
+    input.lines().fold((0, 0), |(line_count, max_width), line| {
+        (line_count + 1, max_width.max(line.chars().count()))
+    })
+}
+
+/// Removes the longest leading whitespace prefix shared by all lines, e.g. when a multi-line string
+/// literal is indented to match the code. Whitespace-only lines don't affect the shared prefix.
+///
+/// Unlike [str::trim], the whitespaces at the end of each line are preserved, which matters for
+/// fixed-width inputs (e.g. day 6).
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::parse::dedent;
+///
+/// assert_eq!(dedent("    12 \n     3 \n    *  "), "12 \n 3 \n*  ");
+/// ```
+pub fn dedent(input: &str) -> String {
+    let leading_whitespace_count =
+        |line: &str| line.chars().take_while(|ch| ch.is_whitespace()).count();
+    let prefix_len = input
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(leading_whitespace_count)
+        .min()
+        .unwrap_or_default();
+
+    input
+        .split('\n')
+        .map(|line| {
+            let skip_count = prefix_len.min(leading_whitespace_count(line));
+            line.chars().skip(skip_count).collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Splits the input into blocks separated by blank (or whitespace-only) lines. Leading and trailing
+/// blank lines are ignored.
+///
+/// Like [dedent], the whitespaces at the end of each line are preserved.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::parse::split_blocks;
+///
+/// assert_eq!(split_blocks("ab\ncd \n\n\nef\n"), vec!["ab\ncd ", "ef"]);
+/// ```
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    // Byte range of the current block
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            let (start, _) = block.unwrap_or((offset, offset));
+            block = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = block {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +200,96 @@ mod tests {
             404919393645906..=405195345919978
         );
     }
+
+    #[test]
+    fn test_parse_i64_number_range() {
+        assert_eq!(parse_i64_number_range("5-10").unwrap(), 5..=10);
+        assert_eq!(parse_i64_number_range("-3-4").unwrap(), -3..=4);
+        assert_eq!(parse_i64_number_range("-10--2").unwrap(), -10..=-2);
+        assert_eq!(parse_i64_number_range(" -1 - 2 ").unwrap(), -1..=2);
+
+        assert!(parse_i64_number_range("").is_err());
+        assert!(parse_i64_number_range("-5").is_err());
+        assert!(parse_i64_number_range("5-").is_err());
+        assert!(parse_i64_number_range("5-x").is_err());
+        assert!(parse_i64_number_range("1-2-3").is_err());
+        assert_eq!(
+            parse_i64_number_range("5_10").unwrap_err().to_string(),
+            "Range '5_10' should be delimited by `-`"
+        );
+    }
+
+    #[test]
+    fn test_parse_number_grid() {
+        let input = "1 2 3\n4 5 6\n7 8 9";
+        let grid = parse_number_grid(input).unwrap();
+        assert_eq!(grid.size(), (3, 3));
+        assert_eq!(grid, grid::grid![[1, 2, 3][4, 5, 6][7, 8, 9]]);
+
+        // Negative numbers and uneven spacing
+        let input = "  -12  3\n4\t-567  ";
+        assert_eq!(
+            parse_number_grid(input).unwrap(),
+            grid::grid![[-12, 3][4, -567]]
+        );
+
+        assert_eq!(
+            parse_number_grid("1 2 3\n4 5\n6 7 8")
+                .unwrap_err()
+                .to_string(),
+            "Expected 3 numbers in each line, but found 2 at line 2"
+        );
+        assert_eq!(
+            parse_number_grid("1 2\n3 x").unwrap_err().to_string(),
+            "Invalid number 'x' at line 2"
+        );
+
+        let grid = parse_number_grid("").unwrap();
+        assert!(grid.is_empty());
+        assert_eq!(grid.size(), (0, 0));
+    }
+
+    #[test]
+    fn test_input_dimensions() {
+        assert_eq!(input_dimensions("0011\n0101"), (2, 4));
+        assert_eq!(input_dimensions("..S\n.\n.^.^  \n"), (3, 6));
+        assert_eq!(input_dimensions("\n\nab"), (3, 2));
+        assert_eq!(input_dimensions(""), (0, 0));
+    }
+
+    #[test]
+    fn test_dedent() {
+        let input = "
+            123 328  51 64 
+             45 64  387 23 
+              6 98  215 314
+            *   +   *   +  ";
+        assert_eq!(
+            dedent(input),
+            "\n123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  "
+        );
+
+        // Whitespace-only lines don't count towards the shared prefix
+        assert_eq!(dedent("    ab\n\n      \n      cd  "), "ab\n\n  \n  cd  ");
+
+        assert_eq!(dedent("ab\n  cd"), "ab\n  cd");
+        assert_eq!(dedent(""), "");
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        assert_eq!(
+            split_blocks("ab\ncd\n\nef\n\ngh\nij"),
+            vec!["ab\ncd", "ef", "gh\nij"]
+        );
+        // Whitespace-only lines are separators, but trailing whitespaces of a line are kept
+        assert_eq!(
+            split_blocks("\n\n12 \n *\n   \n\n3  \n+  \n\n"),
+            vec!["12 \n *", "3  \n+  "]
+        );
+        assert_eq!(split_blocks("ab\r\n\r\ncd\r\n"), vec!["ab", "cd"]);
+        assert_eq!(split_blocks("ab"), vec!["ab"]);
+        assert_eq!(split_blocks("\n  \n"), Vec::<&str>::new());
+        assert_eq!(split_blocks(""), Vec::<&str>::new());
+    }
 }
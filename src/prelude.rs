@@ -0,0 +1,23 @@
+//! Commonly used types and functions, so a new day only needs a single import.
+//!
+//! # Example
+//!
+//! ```
+//! use advent_of_code_2025::prelude::*;
+//!
+//! let grid = parse_string_to_grid("ab\ncd", Ok).unwrap();
+//! assert_eq!(grid_to_string(&grid), "ab\ncd");
+//!
+//! let line = Line3D(Coords3D::new(0, 0, 0), Coords3D::new(2, 3, 6));
+//! assert_eq!(line.len(), 7.0);
+//! assert_eq!(Coords2D::new(1, 2).manhattan_distance(&Coords2D::new(4, 6)), 7);
+//! assert_eq!(Part::iter().count(), 2);
+//! ```
+
+pub use crate::{
+    Direction, Part,
+    coords::{Coords2D, Coords3D},
+    grid::{grid_to_string, parse_string_to_grid},
+    input::read_input,
+    line::{Line2D, Line3D},
+};
@@ -0,0 +1,66 @@
+//! Utilities for generating synthetic puzzle inputs in tests and benchmarks.
+
+/// Generates a grid string of `rows` lines, each with `cols` characters randomly picked from
+/// `chars`. The output only depends on `seed`, so the same seed always gives the same grid.
+///
+/// # Panic
+///
+/// Panics if `chars` is empty while the grid is non-empty.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::test_support::random_grid_string;
+///
+/// let grid = random_grid_string(3, 4, &['.', '@'], 42);
+/// assert_eq!(grid, random_grid_string(3, 4, &['.', '@'], 42));
+/// assert_eq!(grid.lines().count(), 3);
+/// ```
+pub fn random_grid_string(rows: usize, cols: usize, chars: &[char], seed: u64) -> String {
+    let mut state = seed;
+    // SplitMix64: https://prng.di.unimi.it/splitmix64.c
+    let mut next_random = || {
+        state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    };
+
+    (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| chars[(next_random() % chars.len() as u64) as usize])
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_random_grid_string() {
+        let chars = ['.', '@', '#'];
+        let grid = random_grid_string(20, 30, &chars, 2025);
+
+        // Deterministic
+        assert_eq!(grid, random_grid_string(20, 30, &chars, 2025));
+        assert_ne!(grid, random_grid_string(20, 30, &chars, 2026));
+
+        // Dimensions
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(lines.len(), 20);
+        assert!(lines.iter().all(|line| line.chars().count() == 30));
+
+        // Only uses the given characters, and each of them appears
+        assert!(grid.chars().all(|ch| ch == '\n' || chars.contains(&ch)));
+        assert!(chars.iter().all(|ch| grid.contains(*ch)));
+
+        assert_eq!(random_grid_string(0, 5, &chars, 1), "");
+        assert_eq!(random_grid_string(2, 3, &['x'], 1), "xxx\nxxx");
+    }
+}
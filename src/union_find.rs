@@ -0,0 +1,192 @@
+//! [Disjoint-set](https://en.wikipedia.org/wiki/Disjoint-set_data_structure) data structure.
+
+/// Keeps track of elements `0..len` partitioned into disjoint sets, where each set is identified by
+/// one of its elements (the root).
+///
+/// It uses path compression and union by rank, so [Self::find] and [Self::union] take nearly
+/// constant amortized time.
+///
+/// # Example
+///
+/// ```
+/// use advent_of_code_2025::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(1, 2));
+/// assert!(!sets.union(0, 2)); // already in the same set
+/// assert_eq!(sets.count_sets(), 2);
+/// assert_eq!(sets.set_sizes(), vec![3, 1]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    /// Parent of each element. An element is the root of its set if it's its own parent.
+    parents: Vec<usize>,
+    /// Upper bound of the height of the tree of each root.
+    ranks: Vec<u32>,
+    /// Number of elements in the set of each root. Only valid for roots.
+    sizes: Vec<usize>,
+    count_sets: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` sets with one element each.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            count_sets: len,
+        }
+    }
+
+    /// Number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Finds the root of the set containing `element`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is out of bounds.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression: point every element on the path directly to the root
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Joins the sets containing `a` and `b`. Returns whether they were in different sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        // Union by rank: attach the shorter tree under the taller one
+        let (child, parent) = if self.ranks[root_a] < self.ranks[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        if self.ranks[child] == self.ranks[parent] {
+            self.ranks[parent] += 1;
+        }
+        self.count_sets -= 1;
+        true
+    }
+
+    /// Number of disjoint sets.
+    #[inline]
+    pub fn count_sets(&self) -> usize {
+        self.count_sets
+    }
+
+    /// Size of each set, in ascending order of the set's root.
+    pub fn set_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&element| self.parents[element] == element)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() {
+        let mut sets = UnionFind::new(3);
+        assert_eq!(sets.len(), 3);
+        assert_eq!(sets.count_sets(), 3);
+        assert_eq!(sets.set_sizes(), vec![1, 1, 1]);
+        assert_eq!((0..3).map(|i| sets.find(i)).collect::<Vec<_>>(), [0, 1, 2]);
+
+        let sets = UnionFind::new(0);
+        assert!(sets.is_empty());
+        assert_eq!(sets.count_sets(), 0);
+        assert_eq!(sets.set_sizes(), vec![]);
+    }
+
+    #[test]
+    fn test_union_and_find() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(3, 4));
+        assert_eq!(sets.count_sets(), 3);
+        assert_eq!(sets.find(0), sets.find(1));
+        assert_eq!(sets.find(2), sets.find(4));
+        assert_ne!(sets.find(0), sets.find(2));
+        assert_ne!(sets.find(5), sets.find(0));
+
+        // Already in the same set
+        assert!(!sets.union(4, 2));
+        assert!(!sets.union(1, 1));
+        assert_eq!(sets.count_sets(), 3);
+
+        assert!(sets.union(1, 4));
+        assert_eq!(sets.count_sets(), 2);
+        let root = sets.find(0);
+        assert!((0..5).all(|i| sets.find(i) == root));
+        assert_ne!(sets.find(5), root);
+    }
+
+    #[test]
+    fn test_set_sizes() {
+        let mut sets = UnionFind::new(7);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(3, 4);
+        sets.union(4, 5);
+        let mut sizes = sets.set_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 2, 4]);
+        assert_eq!(sizes.iter().sum::<usize>(), sets.len());
+
+        sets.union(6, 0);
+        sets.union(6, 5);
+        assert_eq!(sets.set_sizes(), vec![7]);
+        assert_eq!(sets.count_sets(), 1);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Path compression and union by rank keep this fast
+        let len = 100_000;
+        let mut sets = UnionFind::new(len);
+        for i in 1..len {
+            assert!(sets.union(i - 1, i));
+        }
+        assert_eq!(sets.count_sets(), 1);
+        let root = sets.find(len - 1);
+        assert_eq!(sets.find(0), root);
+        assert_eq!(sets.set_sizes(), vec![len]);
+    }
+}